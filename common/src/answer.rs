@@ -0,0 +1,82 @@
+use std::fmt;
+
+/// Puzzle answer, as it would be submitted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{}", value),
+            Self::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($int: ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Self::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_owned())
+    }
+}
+
+/// Typed answer along with the human-readable message describing it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub answer: Answer,
+    pub message: String,
+}
+
+impl Solved {
+    pub fn new(answer: impl Into<Answer>, message: impl Into<String>) -> Self {
+        Self {
+            answer: answer.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Solved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(-42i32).to_string(), "-42");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+
+        let solved = Solved::new(944u32, "max ID is 944");
+
+        assert_eq!(solved.answer, Answer::Integer(944));
+        assert_eq!(solved.to_string(), "max ID is 944");
+    }
+}
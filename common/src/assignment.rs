@@ -0,0 +1,125 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+use displaydoc::Display;
+use thiserror::Error;
+
+/// Failed to deduce a unique assignment
+#[derive(Debug, Clone, Display, Error, PartialEq, Eq)]
+pub enum AssignmentError {
+    /// no candidates left for {0:?}
+    NoCandidates(String),
+    /// {0:?} can't be told apart by elimination
+    Ambiguous(Vec<String>),
+    /// candidates for {0:?} are given more than once
+    DuplicateKey(String),
+}
+
+/// Matches every key with a distinct value out of its candidates, by repeatedly fixing keys
+/// with a single candidate left and removing that value from the other keys' candidates
+pub fn assign<K, V>(
+    candidates: impl IntoIterator<Item = (K, BTreeSet<V>)>,
+) -> Result<BTreeMap<K, V>, AssignmentError>
+where
+    K: Ord + Clone + Display,
+    V: Ord + Clone,
+{
+    let mut unassigned = BTreeMap::new();
+    for (key, values) in candidates {
+        if unassigned.contains_key(&key) {
+            return Err(AssignmentError::DuplicateKey(key.to_string()));
+        }
+        unassigned.insert(key, values);
+    }
+    let mut assignment = BTreeMap::new();
+
+    loop {
+        if let Some((key, _)) = unassigned.iter().find(|(_, values)| values.is_empty()) {
+            return Err(AssignmentError::NoCandidates(key.to_string()));
+        }
+
+        let (key, value) = match unassigned.iter().find(|(_, values)| values.len() == 1) {
+            Some((key, values)) => (
+                key.clone(),
+                values.first().expect("single candidate").clone(),
+            ),
+            None => break,
+        };
+
+        unassigned.remove(&key);
+        unassigned.values_mut().for_each(|values| {
+            values.remove(&value);
+        });
+        assignment.insert(key, value);
+    }
+
+    if !unassigned.is_empty() {
+        return Err(AssignmentError::Ambiguous(
+            unassigned.keys().map(ToString::to_string).collect(),
+        ));
+    }
+
+    Ok(assignment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assign() {
+        let assignment = assign([
+            ("class", BTreeSet::from([1, 2])),
+            ("row", BTreeSet::from([0, 1, 2])),
+            ("seat", BTreeSet::from([2])),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            assignment,
+            BTreeMap::from([("class", 1), ("row", 0), ("seat", 2)])
+        );
+    }
+
+    #[test]
+    fn test_unresolved() {
+        let e = assign([
+            ("class", BTreeSet::from([0, 1])),
+            ("row", BTreeSet::from([0, 1])),
+            ("seat", BTreeSet::from([2])),
+        ])
+        .unwrap_err();
+
+        assert_eq!(
+            e,
+            AssignmentError::Ambiguous(vec!["class".to_owned(), "row".to_owned()])
+        );
+        assert_eq!(
+            e.to_string(),
+            "[\"class\", \"row\"] can't be told apart by elimination"
+        );
+
+        assert_eq!(
+            assign([("row", BTreeSet::from([0])), ("seat", BTreeSet::from([0]))]),
+            Err(AssignmentError::NoCandidates("seat".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_duplicate_key() {
+        let e = assign([
+            ("row", BTreeSet::from([0])),
+            ("seat", BTreeSet::from([1])),
+            ("row", BTreeSet::from([1])),
+        ])
+        .unwrap_err();
+
+        assert_eq!(e, AssignmentError::DuplicateKey("row".to_owned()));
+        assert_eq!(
+            e.to_string(),
+            "candidates for \"row\" are given more than once"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use advent_utils::Part;
+use displaydoc::Display;
+use thiserror::Error;
+
+/// Reasons for a day to fail producing an answer
+#[derive(Debug, Clone, Display, Error, PartialEq, Eq)]
+pub enum SolveError {
+    /// failed to parse input: {0}
+    Parse(String),
+    /// no solution: {0}
+    NoSolution(String),
+    /// part {0:?} is not implemented
+    NotImplemented(Part),
+}
+
+impl SolveError {
+    pub fn parse(error: impl ToString) -> Self {
+        Self::Parse(error.to_string())
+    }
+
+    pub fn no_solution(reason: impl Into<String>) -> Self {
+        Self::NoSolution(reason.into())
+    }
+}
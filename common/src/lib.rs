@@ -0,0 +1,28 @@
+//! Building blocks shared by all days
+
+use advent_utils::Part;
+
+mod answer;
+mod assignment;
+mod error;
+mod orientation;
+mod parse;
+
+pub use answer::{Answer, Solved};
+pub use assignment::{assign, AssignmentError};
+pub use error::SolveError;
+pub use orientation::Orientation;
+pub use parse::{parse_lines, Located, ParseError};
+
+/// Solver exposing a typed answer alongside its human-readable message
+pub trait TypedSolver {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError>;
+
+    /// Human-readable outcome of solving `part`, be it an answer or an error
+    fn describe(&self, part: Part) -> String {
+        match self.solve_typed(part) {
+            Ok(solved) => solved.message,
+            Err(e) => format!("error: {}", e),
+        }
+    }
+}
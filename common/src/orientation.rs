@@ -0,0 +1,123 @@
+/// One of the 8 ways to place a 2D grid: an optional horizontal flip followed by
+/// up to three clockwise quarter turns
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct Orientation {
+    flipped: bool,
+    quarter_turns: u8,
+}
+
+impl Orientation {
+    pub const IDENTITY: Self = Self {
+        flipped: false,
+        quarter_turns: 0,
+    };
+
+    pub const ALL: [Self; 8] = [
+        Self::new(false, 0),
+        Self::new(false, 1),
+        Self::new(false, 2),
+        Self::new(false, 3),
+        Self::new(true, 0),
+        Self::new(true, 1),
+        Self::new(true, 2),
+        Self::new(true, 3),
+    ];
+
+    /// `quarter_turns` are counted clockwise, applied after the flip
+    pub const fn new(flipped: bool, quarter_turns: u8) -> Self {
+        Self {
+            flipped,
+            quarter_turns: quarter_turns % 4,
+        }
+    }
+
+    /// Size of a `width` x `height` grid after being placed in this orientation
+    pub fn dimensions(self, width: usize, height: usize) -> (usize, usize) {
+        if self.quarter_turns.is_multiple_of(2) {
+            (width, height)
+        } else {
+            (height, width)
+        }
+    }
+
+    /// Position in the original grid of the cell which ends up at `(x, y)`
+    ///
+    /// `width` and `height` are the dimensions of the transformed grid
+    pub fn source(self, (x, y): (usize, usize), (width, height): (usize, usize)) -> (usize, usize) {
+        let (mut x, mut y) = (x, y);
+        let (mut width, mut height) = (width, height);
+
+        for _ in 0..self.quarter_turns {
+            (x, y) = (y, width - 1 - x);
+            (width, height) = (height, width);
+        }
+
+        if self.flipped {
+            x = width - 1 - x;
+        }
+
+        (x, y)
+    }
+
+    /// Transforms row-major `cells` of a grid `width` cells wide, returns new cells and width
+    pub fn apply<T: Clone>(self, cells: &[T], width: usize) -> (Vec<T>, usize) {
+        let height = cells.len().checked_div(width).unwrap_or_default();
+        let (new_width, new_height) = self.dimensions(width, height);
+
+        let cells = (0..new_height)
+            .flat_map(|y| (0..new_width).map(move |x| (x, y)))
+            .map(|position| {
+                let (x, y) = self.source(position, (new_width, new_height));
+
+                cells[x + y * width].clone()
+            })
+            .collect();
+
+        (cells, new_width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        // 1 2 3
+        // 4 5 6
+        let cells = [1, 2, 3, 4, 5, 6];
+
+        assert_eq!(Orientation::IDENTITY.apply(&cells, 3), (cells.to_vec(), 3));
+        assert_eq!(
+            Orientation::new(false, 1).apply(&cells, 3),
+            (vec![4, 1, 5, 2, 6, 3], 2)
+        );
+        assert_eq!(
+            Orientation::new(false, 2).apply(&cells, 3),
+            (vec![6, 5, 4, 3, 2, 1], 3)
+        );
+        assert_eq!(
+            Orientation::new(true, 0).apply(&cells, 3),
+            (vec![3, 2, 1, 6, 5, 4], 3)
+        );
+        assert_eq!(
+            Orientation::new(true, 1).apply(&cells, 3),
+            (vec![6, 3, 5, 2, 4, 1], 2)
+        );
+    }
+
+    #[test]
+    fn test_all_distinct() {
+        let cells = [1, 2, 3, 4, 5, 6];
+
+        let placements = Orientation::ALL
+            .iter()
+            .map(|orientation| orientation.apply(&cells, 3))
+            .collect::<HashSet<_>>();
+
+        assert_eq!(placements.len(), 8);
+        assert_eq!(Orientation::new(true, 5), Orientation::new(true, 1));
+    }
+}
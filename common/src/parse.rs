@@ -0,0 +1,168 @@
+use std::{error::Error, fmt, num::ParseIntError, str::FromStr};
+
+/// Parse errors which know where in the parsed string they've occurred
+pub trait Located {
+    /// 0-based char offset of the offending text, if it's known
+    fn offset(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl Located for ParseIntError {}
+
+/// Parse failure pointing at the offending line (and column) of the input
+#[derive(Debug)]
+pub struct ParseError {
+    line: usize,
+    column: Option<usize>,
+    text: String,
+    source: Box<dyn Error + Send + Sync>,
+}
+
+impl ParseError {
+    /// `line` is 1-based, `text` is the offending line itself
+    pub fn new(line: usize, text: &str, source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self {
+            line,
+            column: None,
+            text: text.to_owned(),
+            source: source.into(),
+        }
+    }
+
+    /// Error at line `line`, with the column derived from `source`
+    pub fn located<E>(line: usize, text: &str, source: E) -> Self
+    where
+        E: Located + Error + Send + Sync + 'static,
+    {
+        let offset = source.offset();
+
+        Self {
+            column: offset.map(|offset| offset + 1),
+            ..Self::new(line, text, source)
+        }
+    }
+
+    /// Points the error at the 0-based char `offset` of the line
+    pub fn at_offset(self, offset: usize) -> Self {
+        Self {
+            column: Some(offset + 1),
+            ..self
+        }
+    }
+
+    /// 1-based line number
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 1-based column number, if it's known
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    /// The offending line
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}", self.line)?;
+
+        if let Some(column) = self.column {
+            write!(f, ", column {}", column)?;
+        }
+
+        write!(f, ": {} (in {:?})", self.source, self.text)
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// Parses every non-empty line of `data`, reporting the location of the first failure
+pub fn parse_lines<T>(data: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Located + Error + Send + Sync + 'static,
+{
+    data.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| {
+            line.parse()
+                .map_err(|e| ParseError::located(idx + 1, line, e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use displaydoc::Display;
+    use indoc::indoc;
+    use thiserror::Error;
+
+    #[derive(Debug, Display, Error)]
+    /// Unexpected char: {0:?}
+    struct UnexpectedChar(char, usize);
+
+    impl Located for UnexpectedChar {
+        fn offset(&self) -> Option<usize> {
+            Some(self.1)
+        }
+    }
+
+    #[derive(Debug)]
+    struct Digits;
+
+    impl FromStr for Digits {
+        type Err = UnexpectedChar;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                Some((idx, c)) => Err(UnexpectedChar(c, idx)),
+                None => Ok(Self),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let numbers: Vec<u32> = parse_lines("1\n\n2\n3\n").unwrap();
+
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_error_location() {
+        let e = parse_lines::<u32>("1\n\n2x\n").unwrap_err();
+
+        assert_eq!(e.line(), 3);
+        assert_eq!(e.column(), None);
+        assert_eq!(e.text(), "2x");
+        assert_eq!(
+            e.to_string(),
+            "line 3: invalid digit found in string (in \"2x\")"
+        );
+
+        let input = indoc!(
+            "123
+            45a6"
+        );
+        let e = parse_lines::<Digits>(input).unwrap_err();
+
+        assert_eq!(e.line(), 2);
+        assert_eq!(e.column(), Some(3));
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 3: Unexpected char: 'a' (in \"45a6\")"
+        );
+    }
+}
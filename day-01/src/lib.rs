@@ -1,6 +1,7 @@
 use std::{collections::BTreeSet, error::Error, str::FromStr};
 
-use advent_utils::{parse_raw_data, Part, Solver};
+use advent_utils::{Part, Solver};
+use common::{parse_lines, SolveError, Solved, TypedSolver};
 
 const TARGET_SUM: u32 = 2020;
 
@@ -13,7 +14,7 @@ impl FromStr for Solution {
     type Err = Box<dyn Error>;
 
     fn from_str(input_data: &str) -> Result<Self, Self::Err> {
-        let expenses = parse_raw_data(input_data)?;
+        let expenses = parse_lines(input_data)?;
 
         Ok(Self {
             expenses: expenses.into_iter().collect(),
@@ -44,27 +45,39 @@ fn find_three_sum(numbers: &BTreeSet<u32>, target_sum: u32) -> Option<(u32, u32,
         .next()
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
         match part {
             Part::One => {
                 let product = find_two_sum(&self.expenses, TARGET_SUM).map(|(a, b)| a * b);
 
                 match product {
-                    Some(product) => format!("target two-sum product is {}", product),
-                    None => "couldn't find solution".to_owned(),
+                    Some(product) => Ok(Solved::new(
+                        product,
+                        format!("target two-sum product is {}", product),
+                    )),
+                    None => Err(SolveError::no_solution("couldn't find solution")),
                 }
             }
             Part::Two => {
                 let product = find_three_sum(&self.expenses, TARGET_SUM).map(|(a, b, c)| a * b * c);
 
                 match product {
-                    Some(product) => format!("target three-sum product is {}", product),
-                    None => "couldn't find solution".to_owned(),
+                    Some(product) => Ok(Solved::new(
+                        product,
+                        format!("target three-sum product is {}", product),
+                    )),
+                    None => Err(SolveError::no_solution("couldn't find solution")),
                 }
             }
         }
     }
+}
+
+impl Solver for Solution {
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
 
     fn day_number() -> u32 {
         1
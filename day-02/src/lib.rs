@@ -1,6 +1,7 @@
 use std::{error::Error, str::FromStr};
 
-use advent_utils::{parse_raw_data, Part, Solver};
+use advent_utils::{Part, Solver};
+use common::{parse_lines, SolveError, Solved, TypedSolver};
 
 mod password;
 
@@ -15,31 +16,50 @@ impl FromStr for Solution {
     type Err = Box<dyn Error>;
 
     fn from_str(input_data: &str) -> Result<Self, Self::Err> {
-        let records = parse_raw_data(input_data)?;
+        let records = parse_lines(input_data)?;
 
         Ok(Self { records })
     }
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
         match part {
-            Part::One => format!(
-                "there are {} valid passwords (using letters count)",
-                self.records
+            Part::One => {
+                let valid = self
+                    .records
                     .iter()
                     .filter(|r| r.is_valid_by_count())
-                    .count(),
-            ),
-            Part::Two => format!(
-                "there are {} valid passwords (using positional method)",
-                self.records
+                    .count();
+
+                Ok(Solved::new(
+                    valid,
+                    format!("there are {} valid passwords (using letters count)", valid),
+                ))
+            }
+            Part::Two => {
+                let valid = self
+                    .records
                     .iter()
                     .filter(|r| r.is_valid_positional())
-                    .count(),
-            ),
+                    .count();
+
+                Ok(Solved::new(
+                    valid,
+                    format!(
+                        "there are {} valid passwords (using positional method)",
+                        valid
+                    ),
+                ))
+            }
         }
     }
+}
+
+impl Solver for Solution {
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
 
     fn day_number() -> u32 {
         2
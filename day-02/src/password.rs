@@ -1,5 +1,6 @@
 use std::{num::ParseIntError, str::FromStr};
 
+use common::Located;
 use displaydoc::Display;
 use thiserror::Error;
 
@@ -92,6 +93,8 @@ pub(crate) enum RecordParseError {
     UnknownFormat,
 }
 
+impl Located for RecordParseError {}
+
 impl FromStr for Record {
     type Err = RecordParseError;
 
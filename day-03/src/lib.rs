@@ -1,6 +1,7 @@
 use std::{error::Error, str::FromStr};
 
 use advent_utils::{Part, Solver};
+use common::{SolveError, Solved, TypedSolver};
 
 mod world;
 
@@ -24,13 +25,17 @@ impl FromStr for Solution {
 const PART_ONE_VELOCITY: (usize, usize) = (3, 1);
 const PART_TWO_VELICITIES: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
         match part {
             Part::One => {
                 let world = World::new(&self.map, PART_ONE_VELOCITY);
+                let trees = world.count_trees();
 
-                format!("you will encounter {} trees", world.count_trees())
+                Ok(Solved::new(
+                    trees,
+                    format!("you will encounter {} trees", trees),
+                ))
             }
             Part::Two => {
                 let part_two_encounters = PART_TWO_VELICITIES.iter().map(|velocity| {
@@ -38,14 +43,21 @@ impl Solver for Solution {
 
                     world.count_trees()
                 });
+                let product = part_two_encounters.product::<usize>();
 
-                format!(
-                    "encounters count from all routes multiplied: {}",
-                    part_two_encounters.product::<usize>()
-                )
+                Ok(Solved::new(
+                    product,
+                    format!("encounters count from all routes multiplied: {}", product),
+                ))
             }
         }
     }
+}
+
+impl Solver for Solution {
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
 
     fn day_number() -> u32 {
         3
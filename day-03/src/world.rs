@@ -1,5 +1,6 @@
 use std::{ops::Index, str::FromStr};
 
+use common::ParseError;
 use displaydoc::Display;
 use thiserror::Error;
 
@@ -70,7 +71,7 @@ impl<'a> World<'a> {
 pub enum MapParseError {
     /// Map has inconsistent width
     InconsistentWidth,
-    /// Map has unknown symbols
+    /// Map has unknown symbol {0:?}
     UnknownSymbol(char),
     /// Map is empty
     EmptyMap,
@@ -80,30 +81,36 @@ const TREE: char = '#';
 const EMPTY_SPACE: char = '.';
 
 impl FromStr for Map {
-    type Err = MapParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut width = None;
+
         let trees = s
             .lines()
-            .map(|line| {
+            .enumerate()
+            .map(|(idx, line)| {
+                let line_error = |e| ParseError::new(idx + 1, line, e);
+
+                if *width.get_or_insert(line.len()) != line.len() {
+                    return Err(line_error(MapParseError::InconsistentWidth));
+                }
+
                 line.chars()
-                    .map(|c| match c {
+                    .enumerate()
+                    .map(|(offset, c)| match c {
                         TREE => Ok(true),
                         EMPTY_SPACE => Ok(false),
-                        c => Err(MapParseError::UnknownSymbol(c)),
+                        c => Err(line_error(MapParseError::UnknownSymbol(c)).at_offset(offset)),
                     })
                     .collect::<Result<Vec<_>, _>>()
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        if trees.windows(2).any(|pair| pair[0].len() != pair[1].len()) {
-            return Err(MapParseError::InconsistentWidth);
-        }
-
-        return Ok(Self {
-            width: trees.get(0).ok_or(MapParseError::EmptyMap)?.len(),
+        Ok(Self {
+            width: width.ok_or_else(|| ParseError::new(1, "", MapParseError::EmptyMap))?,
             trees,
-        });
+        })
     }
 }
 
@@ -145,4 +152,21 @@ mod tests {
 
         assert_eq!(part_two_encounters.product::<usize>(), 336);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "..#\n.#.\n#o.".parse::<Map>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 3, column 2: Map has unknown symbol 'o' (in \"#o.\")"
+        );
+
+        let e = "..#\n.#..".parse::<Map>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 2: Map has inconsistent width (in \".#..\")"
+        );
+    }
 }
@@ -1,6 +1,7 @@
 use std::{error::Error, str::FromStr};
 
 use advent_utils::{Part, Solver};
+use common::{SolveError, Solved, TypedSolver};
 
 mod passport;
 
@@ -21,21 +22,32 @@ impl FromStr for Solution {
     }
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
         let passports = self.passports_raw.split("\n\n").map(Passport::new);
 
         match part {
-            Part::One => format!(
-                "{} passports contains required fields",
-                passports.filter(|p| p.contains_required_fields()).count()
-            ),
-            Part::Two => format!(
-                "{} passports are valid",
-                passports.filter(|p| p.is_valid()).count()
-            ),
+            Part::One => {
+                let count = passports.filter(|p| p.contains_required_fields()).count();
+
+                Ok(Solved::new(
+                    count,
+                    format!("{} passports contains required fields", count),
+                ))
+            }
+            Part::Two => {
+                let count = passports.filter(|p| p.is_valid()).count();
+
+                Ok(Solved::new(count, format!("{} passports are valid", count)))
+            }
         }
     }
+}
+
+impl Solver for Solution {
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
 
     fn day_number() -> u32 {
         4
@@ -9,7 +9,7 @@ impl<'a> Passport<'a> {
     pub(crate) fn new(raw_data: &'a str) -> Self {
         let mut map = BTreeMap::new();
 
-        raw_data.split(|c| c == ' ' || c == '\n').for_each(|field| {
+        raw_data.split([' ', '\n']).for_each(|field| {
             let mut data = field.split(':');
 
             if let Some(key) = data.next() {
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use common::Located;
 use displaydoc::Display;
 use thiserror::Error;
 
@@ -9,34 +10,46 @@ pub(crate) struct BoardingPass {
     seat: u32,
 }
 
+const PASS_LENGTH: usize = 10;
+const ROW_LENGTH: usize = 7;
+
 #[derive(Debug, Display, Error)]
 /// Unknown boarding pass format
-pub(crate) struct UnknownFormatError;
+pub(crate) enum BoardingPassParseError {
+    /// Boarding pass must be 10 chars long, got {0}
+    InvalidLength(usize),
+    /// Unexpected char {chr:?}
+    UnexpectedChar { chr: char, offset: usize },
+}
+
+impl Located for BoardingPassParseError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::InvalidLength(_) => None,
+            Self::UnexpectedChar { offset, .. } => Some(offset),
+        }
+    }
+}
 
 impl FromStr for BoardingPass {
-    type Err = UnknownFormatError;
+    type Err = BoardingPassParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let row = s
-            .chars()
-            .take(7)
-            .enumerate()
-            .try_fold(0u32, |acc, (idx, chr)| match chr {
-                'F' => Ok(acc),
-                'B' => Ok(acc | 1 << (6 - idx)),
-                _ => Err(UnknownFormatError),
-            })?;
-
-        let seat =
-            s[7..]
-                .chars()
-                .take(3)
-                .enumerate()
-                .try_fold(0u32, |acc, (idx, chr)| match chr {
-                    'L' => Ok(acc),
-                    'R' => Ok(acc | 1 << (2 - idx)),
-                    _ => Err(UnknownFormatError),
-                })?;
+        let length = s.chars().count();
+        if length != PASS_LENGTH {
+            return Err(BoardingPassParseError::InvalidLength(length));
+        }
+
+        let (row, seat) = s.chars().enumerate().try_fold(
+            (0u32, 0u32),
+            |(row, seat), (offset, chr)| match chr {
+                'F' if offset < ROW_LENGTH => Ok((row << 1, seat)),
+                'B' if offset < ROW_LENGTH => Ok((row << 1 | 1, seat)),
+                'L' if offset >= ROW_LENGTH => Ok((row, seat << 1)),
+                'R' if offset >= ROW_LENGTH => Ok((row, seat << 1 | 1)),
+                chr => Err(BoardingPassParseError::UnexpectedChar { chr, offset }),
+            },
+        )?;
 
         Ok(Self { row, seat })
     }
@@ -67,4 +80,19 @@ mod tests {
         assert_pass_id_eq!("FFFBBBFRRR", 119);
         assert_pass_id_eq!("BBFFBBFRLL", 820);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = common::parse_lines::<BoardingPass>("FBFBBFFRLR\nFBFBBFLRLR").unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 7: Unexpected char 'L' (in \"FBFBBFLRLR\")"
+        );
+
+        assert!(matches!(
+            "FBFBBFF".parse::<BoardingPass>(),
+            Err(BoardingPassParseError::InvalidLength(7))
+        ));
+    }
 }
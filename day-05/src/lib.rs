@@ -1,6 +1,7 @@
 use std::{error::Error, str::FromStr};
 
-use advent_utils::{parse_raw_data, Part, Solver};
+use advent_utils::{Part, Solver};
+use common::{parse_lines, SolveError, Solved, TypedSolver};
 
 mod boarding_pass;
 
@@ -15,7 +16,7 @@ impl FromStr for Solution {
     type Err = Box<dyn Error>;
 
     fn from_str(input_data: &str) -> Result<Self, Self::Err> {
-        let passes = parse_raw_data(input_data)?;
+        let passes = parse_lines(input_data)?;
 
         Ok(Self { passes })
     }
@@ -25,8 +26,8 @@ fn sum_up_to_n(n: u32) -> u32 {
     (n * (n + 1)) >> 1
 }
 
-impl Solver for Solution {
-    fn solve(&self, part: Part) -> String {
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
         let ids = self.passes.iter().map(BoardingPass::id).collect::<Vec<_>>();
 
         match part {
@@ -34,8 +35,8 @@ impl Solver for Solution {
                 let max_id = ids.iter().max();
 
                 match max_id {
-                    Some(max_id) => format!("max ID is {}", max_id),
-                    None => "no passes provided".to_owned(),
+                    Some(&max_id) => Ok(Solved::new(max_id, format!("max ID is {}", max_id))),
+                    None => Err(SolveError::no_solution("no passes provided")),
                 }
             }
             Part::Two => {
@@ -46,14 +47,24 @@ impl Solver for Solution {
                     Some((&max_id, &min_id)) => {
                         let expected_sum = sum_up_to_n(max_id) - sum_up_to_n(min_id - 1);
                         let real_sum = ids.iter().sum::<u32>();
+                        let missing_id = expected_sum - real_sum;
 
-                        format!("missing pass ID is {}", expected_sum - real_sum)
+                        Ok(Solved::new(
+                            missing_id,
+                            format!("missing pass ID is {}", missing_id),
+                        ))
                     }
-                    None => "no passes provided".to_owned(),
+                    None => Err(SolveError::no_solution("no passes provided")),
                 }
             }
         }
     }
+}
+
+impl Solver for Solution {
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
 
     fn day_number() -> u32 {
         5
@@ -1,10 +1,11 @@
 use std::{error::Error, str::FromStr};
 
 use advent_utils::{Part, Solver};
+use common::{ParseError, SolveError, Solved, TypedSolver};
 
 mod questionnaire;
 
-use questionnaire::Group;
+use questionnaire::{Group, Questionnaire};
 
 #[derive(Debug)]
 pub struct Solution {
@@ -15,10 +16,26 @@ impl FromStr for Solution {
     type Err = Box<dyn Error>;
 
     fn from_str(input_data: &str) -> Result<Self, Self::Err> {
-        let groups = input_data
-            .split("\n\n")
-            .map(str::parse)
-            .collect::<Result<Vec<_>, _>>()?;
+        let mut forms = vec![Vec::new()];
+
+        for (idx, line) in input_data.lines().enumerate() {
+            if line.is_empty() {
+                forms.push(Vec::new());
+                continue;
+            }
+
+            let form = line
+                .parse::<Questionnaire>()
+                .map_err(|e| ParseError::located(idx + 1, line, e))?;
+
+            forms.last_mut().expect("always has a group").push(form);
+        }
+
+        let groups = forms
+            .into_iter()
+            .filter(|forms| !forms.is_empty())
+            .map(Group::from)
+            .collect();
 
         Ok(Self { groups })
     }
@@ -30,15 +47,29 @@ impl Solver for Solution {
     }
 
     fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
         match part {
-            Part::One => format!(
-                "sum of 'yes' counts in all groups (ANY): {}",
-                self.groups.iter().map(|g| g.count_yes_any()).sum::<u32>()
-            ),
-            Part::Two => format!(
-                "sum of 'yes' counts in all groups (ALL): {}",
-                self.groups.iter().map(|g| g.count_yes_all()).sum::<u32>()
-            ),
+            Part::One => {
+                let sum = self.groups.iter().map(|g| g.count_yes_any()).sum::<u32>();
+
+                Ok(Solved::new(
+                    sum,
+                    format!("sum of 'yes' counts in all groups (ANY): {}", sum),
+                ))
+            }
+            Part::Two => {
+                let sum = self.groups.iter().map(|g| g.count_yes_all()).sum::<u32>();
+
+                Ok(Solved::new(
+                    sum,
+                    format!("sum of 'yes' counts in all groups (ALL): {}", sum),
+                ))
+            }
         }
     }
 }
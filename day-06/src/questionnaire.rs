@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use common::Located;
 use displaydoc::Display;
 use thiserror::Error;
 
@@ -13,25 +14,32 @@ pub(crate) struct Group {
 
 #[derive(Debug, Display, Error)]
 pub(crate) enum QuestionParseError {
-    /// Got invalid answer specifier: {0}
-    InvalidAnswer(char),
+    /// Got invalid answer specifier: {answer}
+    InvalidAnswer { answer: char, offset: usize },
+}
+
+impl Located for QuestionParseError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::InvalidAnswer { offset, .. } => Some(offset),
+        }
+    }
 }
 
 impl FromStr for Questionnaire {
     type Err = QuestionParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(s.chars().try_fold(0u32, |answers, answer| {
-            let answer_number = u32::from(answer)
-                .checked_sub(u32::from('a'))
-                .ok_or(QuestionParseError::InvalidAnswer(answer))?;
-
-            let answer_bit = 1u32
-                .checked_shl(answer_number)
-                .ok_or(QuestionParseError::InvalidAnswer(answer))?;
-
-            Ok(answers | answer_bit)
-        })?))
+        Ok(Self(s.chars().enumerate().try_fold(
+            0u32,
+            |answers, (offset, answer)| {
+                if !answer.is_ascii_lowercase() {
+                    return Err(QuestionParseError::InvalidAnswer { answer, offset });
+                }
+
+                Ok(answers | 1 << (u32::from(answer) - u32::from('a')))
+            },
+        )?))
     }
 }
 
@@ -114,4 +122,12 @@ mod tests {
 
         assert_eq!(groups.iter().map(|g| g.count_yes_any()).sum::<u32>(), 11);
     }
+
+    #[test]
+    fn test_invalid_answer() {
+        let e = "abC".parse::<Questionnaire>().unwrap_err();
+
+        assert_eq!(e.to_string(), "Got invalid answer specifier: C");
+        assert_eq!(e.offset(), Some(2));
+    }
 }
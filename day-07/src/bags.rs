@@ -2,6 +2,7 @@ use fxhash::{FxHashMap, FxHashSet};
 
 use std::{convert::TryFrom, iter::FromIterator};
 
+use common::Located;
 use displaydoc::Display;
 use thiserror::Error;
 
@@ -17,6 +18,17 @@ pub(crate) enum RestrictionParseError {
     NoContainer,
     /// Bag content was not specified
     NoContent,
+    /// Invalid bag content: {content}
+    InvalidContent { content: String, offset: usize },
+}
+
+impl Located for RestrictionParseError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::InvalidContent { offset, .. } => Some(offset),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> TryFrom<&'a str> for BagsRestriction<'a> {
@@ -30,30 +42,34 @@ impl<'a> TryFrom<&'a str> for BagsRestriction<'a> {
         let source = data
             .next()
             .ok_or(RestrictionParseError::NoContainer)?
-            .rsplitn(2, ' ')
-            .nth(1)
-            .ok_or(RestrictionParseError::NoContainer)?;
+            .rsplit_once(' ')
+            .ok_or(RestrictionParseError::NoContainer)?
+            .0;
 
         let targets = data
             .next()
             .ok_or(RestrictionParseError::NoContent)?
             .split(", ")
-            .filter_map(|target| {
+            .filter(|target| !target.starts_with("no other"))
+            .map(|target| {
                 // example: 1 bright white bag
                 // example: 2 muted yellow bags.
                 // example: no other bags.
+                let parse_target = || {
+                    let (count, color) = target.split_once(' ')?;
 
-                if target.starts_with("no other") {
-                    return None;
-                }
-
-                let mut parsed = target.splitn(2, ' ');
-
-                let count: u32 = parsed.next()?.parse().ok()?;
+                    Some((count.parse().ok()?, color.rsplit_once(' ')?.0))
+                };
 
-                let color = parsed.next()?.rsplitn(2, ' ').nth(1)?;
+                parse_target().ok_or_else(|| {
+                    // `target` is a substring of `value`
+                    let byte_offset = target.as_ptr() as usize - value.as_ptr() as usize;
 
-                Some(Ok((count, color)))
+                    RestrictionParseError::InvalidContent {
+                        content: target.to_owned(),
+                        offset: value[..byte_offset].chars().count(),
+                    }
+                })
             })
             .collect::<Result<Vec<_>, RestrictionParseError>>()?;
 
@@ -167,6 +183,12 @@ mod tests {
                 content: vec![],
             }
         );
+
+        let e = BagsRestriction::try_from("faded blue bags contain 1 shiny gold bag, many bags.")
+            .unwrap_err();
+
+        assert_eq!(e.to_string(), "Invalid bag content: many bags.");
+        assert_eq!(e.offset(), Some(42));
     }
 
     #[test]
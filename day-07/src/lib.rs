@@ -1,6 +1,7 @@
 use std::{convert::TryFrom, error::Error, str::FromStr};
 
 use advent_utils::{Part, Solver};
+use common::{ParseError, SolveError, Solved, TypedSolver};
 
 mod bags;
 
@@ -13,10 +14,24 @@ pub struct Solution {
     raw_data: String,
 }
 
-impl<'a> FromStr for Solution {
+fn parse_restrictions(raw_data: &str) -> Result<Vec<BagsRestriction<'_>>, ParseError> {
+    raw_data
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| {
+            BagsRestriction::try_from(line).map_err(|e| ParseError::located(idx + 1, line, e))
+        })
+        .collect()
+}
+
+impl FromStr for Solution {
     type Err = Box<dyn Error>;
 
     fn from_str(input_data: &str) -> Result<Self, Self::Err> {
+        // restrictions borrow the raw data, so they're only validated here
+        parse_restrictions(input_data)?;
+
         Ok(Self {
             raw_data: input_data.to_owned(),
         })
@@ -29,28 +44,37 @@ impl Solver for Solution {
     }
 
     fn solve(&self, part: Part) -> String {
-        let restrictions = self
-            .raw_data
-            .lines()
-            .map(BagsRestriction::try_from)
-            .collect::<Result<Vec<_>, _>>();
+        self.describe(part)
+    }
+}
 
-        let graph: RestrictionsGraph<'_> = match restrictions {
-            Ok(restrictions) => restrictions.into_iter().collect(),
-            Err(e) => return format!("error while parsing restrictions: {}", e),
-        };
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        let graph: RestrictionsGraph<'_> = parse_restrictions(&self.raw_data)
+            .map_err(SolveError::parse)?
+            .into_iter()
+            .collect();
 
         match part {
-            Part::One => format!(
-                "there are {} possible containers for {} bag",
-                graph.count_possible_containers(NEEDLE_COLOR),
-                NEEDLE_COLOR,
-            ),
-            Part::Two => format!(
-                "{} bag must contain {} other bags",
-                NEEDLE_COLOR,
-                graph.count_children_bags(NEEDLE_COLOR),
-            ),
+            Part::One => {
+                let containers = graph.count_possible_containers(NEEDLE_COLOR);
+
+                Ok(Solved::new(
+                    containers,
+                    format!(
+                        "there are {} possible containers for {} bag",
+                        containers, NEEDLE_COLOR,
+                    ),
+                ))
+            }
+            Part::Two => {
+                let children = graph.count_children_bags(NEEDLE_COLOR);
+
+                Ok(Solved::new(
+                    children,
+                    format!("{} bag must contain {} other bags", NEEDLE_COLOR, children),
+                ))
+            }
         }
     }
 }
@@ -1,6 +1,7 @@
 use std::{error::Error, str::FromStr};
 
 use advent_utils::{Part, Solver};
+use common::{SolveError, Solved, TypedSolver};
 
 mod vm;
 
@@ -27,16 +28,25 @@ impl Solver for Solution {
     }
 
     fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
         let mut vm = self.vm.clone();
 
         match part {
             Part::One => {
                 vm.detect_loop();
 
-                format!(
-                    "accumulator state before entering infinite loop: {}",
+                Ok(Solved::new(
                     vm.accumulator(),
-                )
+                    format!(
+                        "accumulator state before entering infinite loop: {}",
+                        vm.accumulator(),
+                    ),
+                ))
             }
             Part::Two => {
                 let mut instructions_to_flip = vm
@@ -77,11 +87,16 @@ impl Solver for Solution {
 
                             previous_change.replace(idx);
                         }
-                        None => return "unable to make required change".to_owned(),
+                        None => {
+                            return Err(SolveError::no_solution("unable to make required change"))
+                        }
                     }
                 }
 
-                format!("vm halted with accumulator state {}", vm.accumulator())
+                Ok(Solved::new(
+                    vm.accumulator(),
+                    format!("vm halted with accumulator state {}", vm.accumulator()),
+                ))
             }
         }
     }
@@ -1,5 +1,6 @@
 use std::{num::ParseIntError, str::FromStr};
 
+use common::Located;
 use displaydoc::Display;
 use thiserror::Error;
 
@@ -12,8 +13,8 @@ pub enum Operation {
 
 #[derive(Debug, Display, Error)]
 pub enum ParseOperationError {
-    /// Got unknown operation
-    UnknownOperation,
+    /// Got unknown operation: {0}
+    UnknownOperation(String),
 }
 
 impl FromStr for Operation {
@@ -24,7 +25,7 @@ impl FromStr for Operation {
             "acc" => Ok(Self::Acc),
             "jmp" => Ok(Self::Jmp),
             "nop" => Ok(Self::Nop),
-            _ => Err(ParseOperationError::UnknownOperation),
+            other => Err(ParseOperationError::UnknownOperation(other.to_owned())),
         }
     }
 }
@@ -39,27 +40,41 @@ pub struct Instruction {
 pub enum ParseInstructionError {
     /// Failed to parse operation: {0}
     ParseOperationError(#[from] ParseOperationError),
-    /// Failed to parse argument: {0}
-    ParseIntError(#[from] ParseIntError),
+    /// Failed to parse argument: {source}
+    ParseIntError {
+        source: ParseIntError,
+        offset: usize,
+    },
     /// Unknown instruction format
     UnknownFormat,
 }
 
+impl Located for ParseInstructionError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::ParseOperationError(_) => Some(0),
+            Self::ParseIntError { offset, .. } => Some(offset),
+            Self::UnknownFormat => None,
+        }
+    }
+}
+
 impl FromStr for Instruction {
     type Err = ParseInstructionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut splitted = s.split(' ');
+        let (operation, argument) = s
+            .split_once(' ')
+            .ok_or(ParseInstructionError::UnknownFormat)?;
 
         Ok(Self {
-            operation: splitted
-                .next()
-                .ok_or(ParseInstructionError::UnknownFormat)?
-                .parse()?,
-            argument: splitted
-                .next()
-                .ok_or(ParseInstructionError::UnknownFormat)?
-                .parse()?,
+            operation: operation.parse()?,
+            argument: argument
+                .parse()
+                .map_err(|source| ParseInstructionError::ParseIntError {
+                    source,
+                    offset: operation.chars().count() + 1,
+                })?,
         })
     }
 }
@@ -2,7 +2,9 @@ mod instruction;
 
 use std::str::FromStr;
 
-pub use instruction::{Instruction, Operation, ParseInstructionError};
+use common::{parse_lines, ParseError};
+
+pub use instruction::{Instruction, Operation};
 
 #[derive(Debug, Clone)]
 pub struct Vm {
@@ -26,7 +28,7 @@ impl Vm {
             };
 
             if current_offset < 0 {
-                self.current -= current_offset.abs() as usize;
+                self.current -= current_offset.unsigned_abs() as usize;
             } else {
                 self.current += current_offset as usize;
             }
@@ -66,10 +68,10 @@ impl Vm {
 }
 
 impl FromStr for Vm {
-    type Err = ParseInstructionError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let instructions = s.lines().map(str::parse).collect::<Result<Vec<_>, _>>()?;
+        let instructions = parse_lines(s)?;
 
         Ok(Self {
             accumulator: 0,
@@ -106,13 +108,28 @@ mod tests {
         assert_eq!(vm.accumulator(), 5);
 
         vm.reset();
-        vm.get_mut_instruction(vm.instructions().len() - 2)
-            .and_then(|i| {
-                i.change_operation(Operation::Nop);
-                Some(i)
-            });
+        if let Some(i) = vm.get_mut_instruction(vm.instructions().len() - 2) {
+            i.change_operation(Operation::Nop);
+        }
         assert!(!vm.detect_loop());
 
         assert_eq!(vm.accumulator(), 8);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "nop +0\nacc 1x".parse::<Vm>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 5: Failed to parse argument: invalid digit found in string (in \"acc 1x\")"
+        );
+
+        let e = "nop +0\nmov +1".parse::<Vm>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 1: Failed to parse operation: Got unknown operation: mov (in \"mov +1\")"
+        );
+    }
 }
@@ -1,6 +1,7 @@
 use std::{error::Error, str::FromStr};
 
-use advent_utils::{parse_raw_data, Part, Solver};
+use advent_utils::{Part, Solver};
+use common::{parse_lines, SolveError, Solved, TypedSolver};
 
 mod xmas;
 
@@ -15,7 +16,7 @@ impl FromStr for Solution {
     type Err = Box<dyn Error>;
 
     fn from_str(input_data: &str) -> Result<Self, Self::Err> {
-        let xmas = parse_raw_data(input_data)?.into();
+        let xmas = parse_lines(input_data)?.into();
 
         Ok(Self { xmas })
     }
@@ -27,14 +28,26 @@ impl Solver for Solution {
     }
 
     fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
         match part {
             Part::One => match self.xmas.find_invalid_number(25) {
-                Some((number, _idx)) => format!("first invalid number is {}", number),
-                None => "no invalid numbers".to_owned(),
+                Some((number, _idx)) => Ok(Solved::new(
+                    number,
+                    format!("first invalid number is {}", number),
+                )),
+                None => Err(SolveError::no_solution("no invalid numbers")),
             },
             Part::Two => match self.xmas.find_encryption_weakness(25) {
-                Some(weakness) => format!("encryption weakness is {}", weakness),
-                None => "no weaknesses!".to_owned(),
+                Some(weakness) => Ok(Solved::new(
+                    weakness,
+                    format!("encryption weakness is {}", weakness),
+                )),
+                None => Err(SolveError::no_solution("no weaknesses!")),
             },
         }
     }
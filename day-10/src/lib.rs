@@ -1,6 +1,7 @@
 use std::{error::Error, str::FromStr};
 
-use advent_utils::{parse_raw_data, Part, Solver};
+use advent_utils::{Part, Solver};
+use common::{parse_lines, SolveError, Solved, TypedSolver};
 
 mod jolts;
 
@@ -15,7 +16,7 @@ impl FromStr for Solution {
     type Err = Box<dyn Error>;
 
     fn from_str(input_data: &str) -> Result<Self, Self::Err> {
-        let jolts = parse_raw_data(input_data)?.into();
+        let jolts = parse_lines(input_data)?.into();
 
         Ok(Self { jolts })
     }
@@ -27,12 +28,29 @@ impl Solver for Solution {
     }
 
     fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
         match part {
-            Part::One => format!("diff count product is: {}", self.jolts.count_diffs()),
-            Part::Two => format!(
-                "there are {} possible paths",
-                self.jolts.clone().count_paths(0)
-            ),
+            Part::One => {
+                let product = self.jolts.count_diffs();
+
+                Ok(Solved::new(
+                    product,
+                    format!("diff count product is: {}", product),
+                ))
+            }
+            Part::Two => {
+                let paths = self.jolts.clone().count_paths(0);
+
+                Ok(Solved::new(
+                    paths,
+                    format!("there are {} possible paths", paths),
+                ))
+            }
         }
     }
 }
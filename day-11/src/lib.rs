@@ -1,6 +1,7 @@
 use std::{error::Error, str::FromStr};
 
 use advent_utils::{Part, Solver};
+use common::{SolveError, Solved, TypedSolver};
 
 mod seats;
 
@@ -27,6 +28,12 @@ impl Solver for Solution {
     }
 
     fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
         let mut grid = self.grid.clone();
 
         match part {
@@ -38,10 +45,13 @@ impl Solver for Solution {
                     .filter(|&&seat| matches!(seat, Seat::Occupied))
                     .count();
 
-                format!(
-                    "there are {} occupied seats after simulation",
-                    occupied_seats
-                )
+                Ok(Solved::new(
+                    occupied_seats,
+                    format!(
+                        "there are {} occupied seats after simulation",
+                        occupied_seats
+                    ),
+                ))
             }
             Part::Two => {
                 grid.run_simulation_complex();
@@ -51,10 +61,13 @@ impl Solver for Solution {
                     .filter(|&&seat| matches!(seat, Seat::Occupied))
                     .count();
 
-                format!(
-                    "there are {} occupied seats after complex simulation",
-                    occupied_seats
-                )
+                Ok(Solved::new(
+                    occupied_seats,
+                    format!(
+                        "there are {} occupied seats after complex simulation",
+                        occupied_seats
+                    ),
+                ))
             }
         }
     }
@@ -1,5 +1,6 @@
 use std::{fmt, str::FromStr};
 
+use common::ParseError;
 use displaydoc::Display;
 use thiserror::Error;
 
@@ -35,30 +36,33 @@ pub(crate) struct Grid {
 }
 
 impl FromStr for Grid {
-    type Err = GridParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut width = None;
 
-        let seats: Vec<Vec<_>> = s
-            .lines()
-            .map(|line| {
-                if let Some(old_width) = width.replace(line.len()) {
-                    if old_width != line.len() {
-                        return Err(GridParseError::InconsistentWidth);
+        let seats: Vec<Vec<_>> =
+            s.lines()
+                .enumerate()
+                .map(|(idx, line)| {
+                    let line_error = |e| ParseError::new(idx + 1, line, e);
+
+                    if *width.get_or_insert(line.len()) != line.len() {
+                        return Err(line_error(GridParseError::InconsistentWidth));
                     }
-                }
 
-                line.chars()
-                    .map(|c| match c {
-                        '.' => Ok(None),
-                        'L' => Ok(Some(Seat::Empty)),
-                        '#' => Ok(Some(Seat::Occupied)),
-                        other => Err(GridParseError::InvalidSeatSpec(other)),
-                    })
-                    .collect::<Result<_, _>>()
-            })
-            .collect::<Result<_, _>>()?;
+                    line.chars()
+                        .enumerate()
+                        .map(|(offset, c)| match c {
+                            '.' => Ok(None),
+                            'L' => Ok(Some(Seat::Empty)),
+                            '#' => Ok(Some(Seat::Occupied)),
+                            other => Err(line_error(GridParseError::InvalidSeatSpec(other))
+                                .at_offset(offset)),
+                        })
+                        .collect::<Result<_, _>>()
+                })
+                .collect::<Result<_, _>>()?;
 
         let seats = seats.into_iter().flatten().collect::<Vec<_>>();
         let spare_vec = seats.clone();
@@ -66,7 +70,7 @@ impl FromStr for Grid {
         Ok(Self {
             seats,
             new_seats: spare_vec,
-            width: width.ok_or(GridParseError::EmptyGrid)?,
+            width: width.ok_or_else(|| ParseError::new(1, "", GridParseError::EmptyGrid))?,
             neighbours_simple: Vec::new(),
             neighbours_complex: Vec::new(),
         })
@@ -336,4 +340,18 @@ mod tests {
 
         assert_eq!(occupied_seats, 26);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "L.L\n.X.".parse::<Grid>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 2: Invalid seat specifier: X (in \".X.\")"
+        );
+
+        let e = "".parse::<Grid>().unwrap_err();
+
+        assert_eq!(e.to_string(), "line 1: Empty grid (in \"\")");
+    }
 }
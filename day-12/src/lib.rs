@@ -1,6 +1,7 @@
 use std::{error::Error, str::FromStr};
 
-use advent_utils::{parse_raw_data, Part, Solver};
+use advent_utils::{Part, Solver};
+use common::{parse_lines, SolveError, Solved, TypedSolver};
 
 mod movement;
 mod ship;
@@ -17,7 +18,7 @@ impl FromStr for Solution {
     type Err = Box<dyn Error>;
 
     fn from_str(input_data: &str) -> Result<Self, Self::Err> {
-        let movements = parse_raw_data(input_data)?;
+        let movements = parse_lines(input_data)?;
 
         Ok(Self { movements })
     }
@@ -29,6 +30,12 @@ impl Solver for Solution {
     }
 
     fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
         let mut basic = BasicShip::new();
         let mut waypointed = WaypointedShip::new();
 
@@ -41,6 +48,11 @@ impl Solver for Solution {
             ship.process_movement(movement);
         }
 
-        format!("ship distance from zero is: {}", ship.manhattan_distance())
+        let distance = ship.manhattan_distance();
+
+        Ok(Solved::new(
+            distance,
+            format!("ship distance from zero is: {}", distance),
+        ))
     }
 }
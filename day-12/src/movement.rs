@@ -1,5 +1,6 @@
 use std::{num::ParseIntError, str::FromStr};
 
+use common::Located;
 use displaydoc::Display;
 use thiserror::Error;
 
@@ -8,7 +9,7 @@ pub(crate) struct Degrees(usize);
 
 impl Degrees {
     pub(super) fn quarters(self) -> usize {
-        debug_assert!(self.0 % 90 == 0);
+        debug_assert!(self.0.is_multiple_of(90));
 
         self.0 / 90
     }
@@ -47,20 +48,31 @@ pub(crate) enum ParseMovementError {
     ParseIntError(#[from] ParseIntError),
 }
 
+impl Located for ParseMovementError {
+    fn offset(&self) -> Option<usize> {
+        match self {
+            Self::EmptyStr => None,
+            Self::InvalidAction(_) => Some(0),
+            Self::ParseIntError(_) => Some(1),
+        }
+    }
+}
+
 impl FromStr for Movement {
     type Err = ParseMovementError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let action = s.chars().next().ok_or(ParseMovementError::EmptyStr)?;
+        let value = &s[action.len_utf8()..];
 
         match action {
-            'N' => Ok(Self::North(s[1..].parse()?)),
-            'E' => Ok(Self::East(s[1..].parse()?)),
-            'S' => Ok(Self::South(s[1..].parse()?)),
-            'W' => Ok(Self::West(s[1..].parse()?)),
-            'R' => Ok(Self::Right(Degrees(s[1..].parse()?))),
-            'L' => Ok(Self::Left(Degrees(s[1..].parse()?))),
-            'F' => Ok(Self::Forward(s[1..].parse()?)),
+            'N' => Ok(Self::North(value.parse()?)),
+            'E' => Ok(Self::East(value.parse()?)),
+            'S' => Ok(Self::South(value.parse()?)),
+            'W' => Ok(Self::West(value.parse()?)),
+            'R' => Ok(Self::Right(Degrees(value.parse()?))),
+            'L' => Ok(Self::Left(Degrees(value.parse()?))),
+            'F' => Ok(Self::Forward(value.parse()?)),
             other => Err(ParseMovementError::InvalidAction(other)),
         }
     }
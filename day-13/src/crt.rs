@@ -0,0 +1,135 @@
+/// Congruence `x ≡ remainder (mod modulus)`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct Congruence {
+    pub(crate) remainder: u128,
+    pub(crate) modulus: u128,
+}
+
+/// Returns `(gcd(a, b), x)` such that `a * x ≡ gcd(a, b) (mod b)`
+fn extended_gcd(a: u128, b: u128) -> (u128, u128) {
+    // Bézout coefficients are tracked modulo `b`, so everything stays unsigned
+    let (mut old_r, mut r) = (a % b, b);
+    let (mut old_x, mut x) = (1 % b, 0);
+
+    while r != 0 {
+        let q = old_r / r;
+
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, sub_mod(old_x, mul_mod(q % b, x, b), b));
+    }
+
+    (old_r, old_x)
+}
+
+fn sub_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        modulus - (b - a)
+    }
+}
+
+/// `a * b mod modulus` without overflowing, for `a, b < modulus`
+fn mul_mod(mut a: u128, mut b: u128, modulus: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, modulus);
+        }
+        a = add_mod(a, a, modulus);
+        b >>= 1;
+    }
+
+    result
+}
+
+fn add_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    sub_mod(a, modulus - b, modulus)
+}
+
+impl Congruence {
+    pub(crate) fn new(remainder: u128, modulus: u128) -> Self {
+        Self {
+            remainder: remainder % modulus,
+            modulus,
+        }
+    }
+
+    /// Congruence satisfied by exactly the numbers satisfying both `self` and `other`.
+    ///
+    /// Moduli don't have to be coprime; returns `None` if the system is inconsistent
+    /// or its modulus (the lcm of both) doesn't fit into `u128`.
+    pub(crate) fn combine(self, other: Self) -> Option<Self> {
+        let (gcd, inverse) = extended_gcd(self.modulus, other.modulus);
+
+        let difference = sub_mod(
+            other.remainder,
+            self.remainder % other.modulus,
+            other.modulus,
+        );
+        if !difference.is_multiple_of(gcd) {
+            return None;
+        }
+
+        // x = self.remainder + self.modulus * k, where
+        // k ≡ (difference / gcd) * inverse (mod other.modulus / gcd)
+        let step = other.modulus / gcd;
+        let k = mul_mod(difference / gcd % step, inverse % step, step);
+        let modulus = self.modulus.checked_mul(step)?;
+
+        Some(Self {
+            remainder: (self.remainder + self.modulus * k) % modulus,
+            modulus,
+        })
+    }
+}
+
+/// Smallest non-negative solution of the system, along with the period of solutions
+pub(crate) fn solve(congruences: impl IntoIterator<Item = Congruence>) -> Option<Congruence> {
+    congruences
+        .into_iter()
+        .try_fold(Congruence::new(0, 1), Congruence::combine)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let system = [
+            Congruence::new(2, 3),
+            Congruence::new(3, 5),
+            Congruence::new(2, 7),
+        ];
+
+        assert_eq!(solve(system), Some(Congruence::new(23, 105)));
+    }
+
+    #[test]
+    fn test_non_coprime() {
+        let system = [Congruence::new(3, 4), Congruence::new(1, 6)];
+
+        assert_eq!(solve(system), Some(Congruence::new(7, 12)));
+
+        let system = [Congruence::new(0, 4), Congruence::new(1, 6)];
+
+        assert_eq!(solve(system), None);
+    }
+
+    #[test]
+    fn test_large_moduli() {
+        let a = u64::MAX as u128 - 58; // both are primes
+        let b = u64::MAX as u128 - 82;
+
+        let solution = solve([Congruence::new(1, a), Congruence::new(2, b)]).unwrap();
+
+        assert_eq!(solution.modulus, a * b);
+        assert_eq!(solution.remainder % a, 1);
+        assert_eq!(solution.remainder % b, 2);
+    }
+}
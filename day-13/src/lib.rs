@@ -1,9 +1,14 @@
 use std::{error::Error, num::ParseIntError, str::FromStr};
 
 use advent_utils::{Part, Solver};
+use common::{Located, ParseError, SolveError, Solved, TypedSolver};
 use displaydoc::Display;
 use thiserror::Error;
 
+mod crt;
+
+use crt::Congruence;
+
 #[derive(Debug)]
 pub struct Solution {
     min_departure_time: u64,
@@ -37,6 +42,49 @@ pub enum TimetableParseError {
     NoTimetable,
     /// Error while partsing departure time: {0}
     DepartureTimeInvalid(#[from] ParseIntError),
+    /// Bus id can't be zero
+    ZeroBusId { offset: usize },
+    /// Invalid bus id {id:?}: {source}
+    BusIdInvalid {
+        id: String,
+        source: ParseIntError,
+        offset: usize,
+    },
+}
+
+impl Located for TimetableParseError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::ZeroBusId { offset } | Self::BusIdInvalid { offset, .. } => Some(offset),
+            _ => None,
+        }
+    }
+}
+
+const OUT_OF_SERVICE: &str = "x";
+
+fn parse_timetable(line: &str) -> Result<Vec<Option<u64>>, TimetableParseError> {
+    let mut offset = 0;
+
+    line.split(',')
+        .map(|id| {
+            let bus = match id {
+                OUT_OF_SERVICE => Ok(None),
+                id => match id.parse() {
+                    Ok(0) => Err(TimetableParseError::ZeroBusId { offset }),
+                    Ok(id) => Ok(Some(id)),
+                    Err(source) => Err(TimetableParseError::BusIdInvalid {
+                        id: id.to_owned(),
+                        source,
+                        offset,
+                    }),
+                },
+            };
+            offset += id.chars().count() + 1;
+
+            bus
+        })
+        .collect()
 }
 
 impl FromStr for Solution {
@@ -44,16 +92,19 @@ impl FromStr for Solution {
 
     fn from_str(input_data: &str) -> Result<Self, Self::Err> {
         let mut lines = input_data.lines();
+
         let departure_time = lines
             .next()
-            .ok_or(TimetableParseError::NoDepartureTime)?
-            .parse()?;
+            .ok_or_else(|| ParseError::new(1, "", TimetableParseError::NoDepartureTime))?;
+        let departure_time = departure_time
+            .parse()
+            .map_err(|e| ParseError::new(1, departure_time, TimetableParseError::from(e)))?;
+
         let timetable = lines
             .next()
-            .ok_or(TimetableParseError::NoTimetable)?
-            .split(',')
-            .map(|data| data.parse::<u64>().ok())
-            .collect();
+            .ok_or_else(|| ParseError::new(2, "", TimetableParseError::NoTimetable))?;
+        let timetable =
+            parse_timetable(timetable).map_err(|e| ParseError::located(2, timetable, e))?;
 
         Ok(Self {
             min_departure_time: departure_time,
@@ -68,6 +119,12 @@ impl Solver for Solution {
     }
 
     fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
         match part {
             Part::One => {
                 let min_id = closest_bus(
@@ -77,7 +134,7 @@ impl Solver for Solution {
 
                 let min_id = match min_id {
                     Some(id) => id,
-                    None => return "no suitable buses :(".to_owned(),
+                    None => return Err(SolveError::no_solution("no suitable buses :(")),
                 };
 
                 let div = self.min_departure_time / min_id;
@@ -89,30 +146,88 @@ impl Solver for Solution {
                     div * min_id
                 };
 
-                format!(
-                    "you will depart in bus №{} at {}. Answer is {}",
-                    min_id,
-                    departure,
-                    min_id * (departure - self.min_departure_time)
-                )
+                let answer = min_id * (departure - self.min_departure_time);
+
+                Ok(Solved::new(
+                    answer,
+                    format!(
+                        "you will depart in bus №{} at {}. Answer is {}",
+                        min_id, departure, answer
+                    ),
+                ))
+            }
+            Part::Two => {
+                let schedule = self
+                    .timetable
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(offset, id)| {
+                        let id = u128::from((*id)?);
+
+                        // bus must depart `offset` minutes after the timestamp
+                        Some(Congruence::new(id - offset as u128 % id, id))
+                    });
+
+                let timestamp = match crt::solve(schedule) {
+                    Some(solution) => solution.remainder,
+                    None => {
+                        return Err(SolveError::no_solution(
+                            "buses never depart at their offsets",
+                        ))
+                    }
+                };
+                let answer = i128::try_from(timestamp).map_err(|_| {
+                    SolveError::no_solution(format!("timestamp {} is too large", timestamp))
+                })?;
+
+                Ok(Solved::new(
+                    answer,
+                    format!("earliest aligned departures start at {}", timestamp),
+                ))
             }
-            Part::Two => unimplemented!(),
         }
     }
-
-    fn implemented_parts() -> Vec<Part> {
-        vec![Part::One]
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use common::Answer;
+
     #[test]
     fn test_closest() {
         let ids: [u64; 5] = [7, 13, 59, 31, 19];
 
         assert_eq!(closest_bus(939, ids.iter()), Some(59));
     }
+
+    #[test]
+    fn test_aligned_departures() {
+        macro_rules! assert_timestamp_eq {
+            ($timetable: expr, $timestamp: expr) => {
+                let solution: Solution = concat!("0\n", $timetable).parse().unwrap();
+                let solved = solution.solve_typed(Part::Two).unwrap();
+
+                assert_eq!(solved.answer, Answer::Integer($timestamp));
+            };
+        }
+
+        assert_timestamp_eq!("7,13,x,x,59,x,31,19", 1068781);
+        assert_timestamp_eq!("17,x,13,19", 3417);
+        assert_timestamp_eq!("67,7,59,61", 754018);
+        assert_timestamp_eq!("67,x,7,59,61", 779210);
+        assert_timestamp_eq!("67,7,x,59,61", 1261476);
+        assert_timestamp_eq!("1789,37,47,1889", 1202161486);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "939\n7,13,x,5g,31".parse::<Solution>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 8: Invalid bus id \"5g\": invalid digit found in string (in \"7,13,x,5g,31\")"
+        );
+    }
 }
@@ -0,0 +1,77 @@
+/// Set of addresses matching a mask pattern: bits from `floating` take any value,
+/// all the others are equal to the ones in `base`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct AddressPattern {
+    base: u64,
+    floating: u64,
+}
+
+impl AddressPattern {
+    pub(crate) fn new(base: u64, floating: u64) -> Self {
+        Self {
+            base: base & !floating,
+            floating,
+        }
+    }
+
+    /// Number of addresses in the set
+    pub(crate) fn len(&self) -> u64 {
+        1 << self.floating.count_ones()
+    }
+
+    pub(crate) fn contains(&self, address: u64) -> bool {
+        address & !self.floating == self.base
+    }
+
+    pub(crate) fn intersects(&self, other: &Self) -> bool {
+        (self.base ^ other.base) & !(self.floating | other.floating) == 0
+    }
+
+    /// Splits `self \ other` into disjoint patterns, at most one per bit
+    /// floating in `self` but fixed in `other`
+    pub(crate) fn subtract(self, other: &Self) -> Vec<Self> {
+        if !self.intersects(other) {
+            return vec![self];
+        }
+
+        let mut rest = self;
+        let mut difference = Vec::new();
+
+        let mut bits = self.floating & !other.floating;
+        while bits != 0 {
+            let bit = bits & bits.wrapping_neg();
+            bits ^= bit;
+
+            // addresses where `bit` differs from `other` are outside of it,
+            // the remaining ones are split further by the next bits
+            let floating = rest.floating & !bit;
+            difference.push(Self::new(rest.base | (!other.base & bit), floating));
+            rest = Self::new(rest.base | (other.base & bit), floating);
+        }
+
+        difference
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subtract() {
+        let all = AddressPattern::new(0, 0b1111);
+        let some = AddressPattern::new(0b0100, 0b1001);
+
+        let difference = all.subtract(&some);
+
+        assert_eq!(difference.iter().map(AddressPattern::len).sum::<u64>(), 12);
+        assert!(difference.iter().all(|pattern| !pattern.intersects(&some)));
+        assert!(difference
+            .iter()
+            .enumerate()
+            .all(|(idx, a)| difference[idx + 1..].iter().all(|b| !a.intersects(b))));
+
+        assert_eq!(some.subtract(&all), vec![]);
+        assert_eq!(some.subtract(&AddressPattern::new(0, 0)), vec![some]);
+    }
+}
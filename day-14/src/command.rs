@@ -1,20 +1,30 @@
 use std::{num::ParseIntError, str::FromStr};
 
+use common::Located;
 use displaydoc::Display;
 use thiserror::Error;
 
+/// `SetMask` keeps `X` bits of the mask in `floating_mask`,
+/// they're also the only bits set in `and_mask` (within 36 bits of the mask)
 #[derive(Debug, Eq, PartialEq)]
 pub(crate) enum Command {
-    SetMask { or_mask: u64, and_mask: u64 },
-    Write { address: usize, value: u64 },
+    SetMask {
+        or_mask: u64,
+        and_mask: u64,
+        floating_mask: u64,
+    },
+    Write {
+        address: usize,
+        value: u64,
+    },
 }
 
 #[derive(Debug, Display, Error)]
 pub(crate) enum ParseCommandError {
     /// Got unknown command
     UnknownCommand,
-    /// Unknown char in mask: {0}
-    UnknownMaskChar(char),
+    /// Unknown char in mask: {chr}
+    UnknownMaskChar { chr: char, offset: usize },
     /// Invalid mask length,
     InvalidMaskLength,
     /// Invalid command format
@@ -23,7 +33,18 @@ pub(crate) enum ParseCommandError {
     ParseIntError(#[from] ParseIntError),
 }
 
+impl Located for ParseCommandError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::UnknownCommand => Some(0),
+            Self::UnknownMaskChar { offset, .. } => Some(offset),
+            _ => None,
+        }
+    }
+}
+
 const BIT_LENGTH: usize = 35;
+const MASK_PREFIX: &str = "mask = ";
 
 impl FromStr for Command {
     type Err = ParseCommandError;
@@ -32,30 +53,36 @@ impl FromStr for Command {
         let mut operands = s.split(" = ");
 
         if s.starts_with("mask") {
-            let mask = operands.nth(1).ok_or(ParseCommandError::InvalidFormat)?;
-
-            let (and_mask, or_mask) = mask.chars().enumerate().try_fold(
-                (std::u64::MAX, 0),
-                |(and_mask, or_mask), (idx, chr)| match chr {
-                    '0' => {
-                        let bit = 1
-                            << BIT_LENGTH
-                                .checked_sub(idx)
-                                .ok_or(ParseCommandError::InvalidMaskLength)?;
-
-                        Ok((and_mask ^ bit, or_mask))
-                    }
-                    '1' => {
-                        let bit = 1 << (BIT_LENGTH - idx);
+            let mask = s
+                .strip_prefix(MASK_PREFIX)
+                .ok_or(ParseCommandError::InvalidFormat)?;
+
+            if mask.len() != BIT_LENGTH + 1 {
+                return Err(ParseCommandError::InvalidMaskLength);
+            }
 
-                        Ok((and_mask ^ bit, or_mask ^ bit))
+            let (and_mask, or_mask, floating_mask) = mask.chars().enumerate().try_fold(
+                (u64::MAX, 0, 0),
+                |(and_mask, or_mask, floating_mask), (idx, chr)| {
+                    let bit = 1 << (BIT_LENGTH - idx);
+
+                    match chr {
+                        '0' => Ok((and_mask ^ bit, or_mask, floating_mask)),
+                        '1' => Ok((and_mask ^ bit, or_mask ^ bit, floating_mask)),
+                        'X' => Ok((and_mask, or_mask, floating_mask ^ bit)),
+                        chr => Err(ParseCommandError::UnknownMaskChar {
+                            chr,
+                            offset: MASK_PREFIX.len() + idx,
+                        }),
                     }
-                    'X' => Ok((and_mask, or_mask)),
-                    other => Err(ParseCommandError::UnknownMaskChar(other)),
                 },
             )?;
 
-            Ok(Self::SetMask { and_mask, or_mask })
+            Ok(Self::SetMask {
+                and_mask,
+                or_mask,
+                floating_mask,
+            })
         } else if s.starts_with("mem") {
             let address_spec = operands.next().ok_or(ParseCommandError::InvalidFormat)?;
             let value = operands
@@ -94,8 +121,29 @@ mod tests {
             cmd,
             Command::SetMask {
                 and_mask: 0b1111111111111111111111111111111111111111111111111111111110111101,
-                or_mask: 64
+                or_mask: 64,
+                floating_mask: 0b111111111111111111111111111110111101,
             }
         );
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = common::parse_lines::<Command>(
+            "mem[8] = 11\nmask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX2X",
+        )
+        .unwrap_err();
+
+        assert_eq!(e.line(), 2);
+        assert_eq!(e.column(), Some(42));
+        assert_eq!(
+            e.to_string().split(" (in").next(),
+            Some("line 2, column 42: Unknown char in mask: 2")
+        );
+
+        assert!(matches!(
+            "mask = X1".parse::<Command>(),
+            Err(ParseCommandError::InvalidMaskLength)
+        ));
+    }
 }
@@ -1,12 +1,14 @@
 use std::{error::Error, str::FromStr};
 
-use advent_utils::{parse_raw_data, Part, Solver};
+use advent_utils::{Part, Solver};
+use common::{parse_lines, SolveError, Solved, TypedSolver};
 
+mod address;
 mod command;
 mod memory;
 
 use command::Command;
-use memory::Memory;
+use memory::{Decoder, Memory};
 
 #[derive(Debug)]
 pub struct Solution {
@@ -18,7 +20,7 @@ impl FromStr for Solution {
 
     fn from_str(input_data: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            commands: parse_raw_data(input_data)?,
+            commands: parse_lines(input_data)?,
         })
     }
 }
@@ -29,22 +31,26 @@ impl Solver for Solution {
     }
 
     fn solve(&self, part: Part) -> String {
-        let mut mem = Memory::new();
-
-        match part {
-            Part::One => {
-                self.commands.iter().for_each(|c| mem.process_command(c));
-
-                format!(
-                    "sum of values in memory: {}",
-                    mem.data().values().sum::<u64>()
-                )
-            }
-            Part::Two => unimplemented!(),
-        }
+        self.describe(part)
     }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        let decoder = match part {
+            Part::One => Decoder::Value,
+            Part::Two => Decoder::Address,
+        };
+
+        let mut mem = Memory::new(decoder);
+        self.commands.iter().for_each(|c| mem.process_command(c));
+
+        // at most 2^36 addresses with u64 values, so it can't overflow
+        let sum = i128::try_from(mem.sum()).expect("sum of memory fits into i128");
 
-    fn implemented_parts() -> Vec<Part> {
-        vec![Part::One]
+        Ok(Solved::new(
+            sum,
+            format!("sum of values in memory: {}", sum),
+        ))
     }
 }
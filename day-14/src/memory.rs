@@ -1,39 +1,123 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, mem};
 
-use crate::command::Command;
+use crate::{address::AddressPattern, command::Command};
+
+/// How the current mask is applied on writes
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Decoder {
+    /// Mask overrides bits of the written value
+    Value,
+    /// Mask overrides bits of the address, `X` bits float over both values
+    Address,
+}
 
 #[derive(Debug)]
 pub(crate) struct Memory {
+    decoder: Decoder,
     and_mask: u64,
     or_mask: u64,
-    data: BTreeMap<usize, u64>,
+    floating_mask: u64,
+    /// Values written to single addresses, none of which is covered by `floating`
+    data: BTreeMap<u64, u64>,
+    /// Disjoint sets of addresses written through floating bits, along with the value stored there
+    floating: Vec<(AddressPattern, u64)>,
 }
 
 impl Memory {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(decoder: Decoder) -> Self {
         Self {
+            decoder,
             and_mask: u64::MAX,
             or_mask: 0,
-            data: Default::default(),
+            floating_mask: 0,
+            data: BTreeMap::new(),
+            floating: Vec::new(),
         }
     }
 
     pub(crate) fn process_command(&mut self, command: &Command) {
         match *command {
-            Command::Write { address, value } => {
-                let value = (value & self.and_mask) | self.or_mask;
-
-                self.data.insert(address, value);
-            }
-            Command::SetMask { and_mask, or_mask } => {
+            Command::Write { address, value } => match self.decoder {
+                Decoder::Value => {
+                    self.write(address as u64, 0, (value & self.and_mask) | self.or_mask)
+                }
+                Decoder::Address => {
+                    self.write(address as u64 | self.or_mask, self.floating_mask, value)
+                }
+            },
+            Command::SetMask {
+                and_mask,
+                or_mask,
+                floating_mask,
+            } => {
                 self.and_mask = and_mask;
                 self.or_mask = or_mask;
+                self.floating_mask = floating_mask;
             }
         }
     }
 
-    pub(crate) fn data(&self) -> &BTreeMap<usize, u64> {
-        &self.data
+    /// Stores `value` at `address` with its `floating` bits taking both values
+    fn write(&mut self, address: u64, floating: u64, value: u64) {
+        let addresses = AddressPattern::new(address, floating);
+
+        // later writes shadow earlier ones; a single address only splits the patterns covering it,
+        // so without floating bits (as in part one) the patterns aren't rebuilt at all
+        if floating != 0
+            || self
+                .floating
+                .iter()
+                .any(|(pattern, _)| pattern.contains(address))
+        {
+            self.floating = mem::take(&mut self.floating)
+                .into_iter()
+                .flat_map(|(pattern, value)| {
+                    pattern
+                        .subtract(&addresses)
+                        .into_iter()
+                        .map(move |pattern| (pattern, value))
+                })
+                .collect();
+        }
+
+        if floating == 0 {
+            self.data.insert(address, value);
+        } else {
+            self.data.retain(|&written, _| !addresses.contains(written));
+            self.floating.push((addresses, value));
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn get(&self, address: u64) -> Option<u64> {
+        self.data.get(&address).copied().or_else(|| {
+            self.floating
+                .iter()
+                .find(|(pattern, _)| pattern.contains(address))
+                .map(|&(_, value)| value)
+        })
+    }
+
+    /// Number of addresses written so far
+    #[cfg(test)]
+    pub(crate) fn address_count(&self) -> u64 {
+        self.data.len() as u64
+            + self
+                .floating
+                .iter()
+                .map(|(pattern, _)| pattern.len())
+                .sum::<u64>()
+    }
+
+    /// Sum of all values stored in memory
+    pub(crate) fn sum(&self) -> u128 {
+        let single = self.data.values().map(|&value| u128::from(value));
+        let floating = self
+            .floating
+            .iter()
+            .map(|&(pattern, value)| u128::from(pattern.len()) * u128::from(value));
+
+        single.chain(floating).sum()
     }
 }
 
@@ -56,11 +140,86 @@ mod tests {
         .collect::<Result<Vec<Command>, _>>()
         .unwrap();
 
-        let mut mem = Memory::new();
+        let mut mem = Memory::new(Decoder::Value);
+        commands.iter().for_each(|c| mem.process_command(c));
+
+        assert_eq!(mem.get(7), Some(101));
+        assert_eq!(mem.get(8), Some(64));
+        assert_eq!(mem.address_count(), 2);
+    }
+
+    #[test]
+    fn test_address_decoder_example() {
+        let commands = indoc!(
+            "mask = 000000000000000000000000000000X1001X
+            mem[42] = 100
+            mask = 00000000000000000000000000000000X0XX
+            mem[26] = 1"
+        )
+        .lines()
+        .map(str::parse)
+        .collect::<Result<Vec<Command>, _>>()
+        .unwrap();
+
+        let mut mem = Memory::new(Decoder::Address);
+        commands.iter().for_each(|c| mem.process_command(c));
+
+        assert_eq!(mem.address_count(), 10);
+        assert_eq!(mem.get(58), Some(100));
+        assert_eq!(mem.get(59), Some(100));
+        assert_eq!(mem.get(16), Some(1));
+        assert_eq!(mem.sum(), 208);
+    }
+
+    #[test]
+    fn test_single_and_floating_writes() {
+        let commands = indoc!(
+            "mask = 00000000000000000000000000000000000X
+            mem[4] = 10
+            mask = 000000000000000000000000000000000000
+            mem[5] = 20
+            mem[6] = 30
+            mask = 0000000000000000000000000000000000X0
+            mem[4] = 40
+            mask = 000000000000000000000000000000000000
+            mem[4] = 50"
+        )
+        .lines()
+        .map(str::parse)
+        .collect::<Result<Vec<Command>, _>>()
+        .unwrap();
+
+        let mut mem = Memory::new(Decoder::Address);
         commands.iter().for_each(|c| mem.process_command(c));
 
-        assert_eq!(mem.data().get(&7), Some(&101));
-        assert_eq!(mem.data().get(&8), Some(&64));
-        assert_eq!(mem.data().len(), 2);
+        assert_eq!(mem.get(4), Some(50));
+        assert_eq!(mem.get(5), Some(20));
+        assert_eq!(mem.get(6), Some(40));
+        assert_eq!(mem.address_count(), 3);
+        assert_eq!(mem.sum(), 110);
+    }
+
+    #[test]
+    fn test_many_floating_bits() {
+        let commands = indoc!(
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX
+            mem[0] = 3
+            mask = 0XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX1
+            mem[0] = 5
+            mask = 1XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX0
+            mem[0] = 7"
+        )
+        .lines()
+        .map(str::parse)
+        .collect::<Result<Vec<Command>, _>>()
+        .unwrap();
+
+        let mut mem = Memory::new(Decoder::Address);
+        commands.iter().for_each(|c| mem.process_command(c));
+
+        let quarter = 1u128 << 34;
+
+        assert_eq!(mem.address_count(), 1 << 36);
+        assert_eq!(mem.sum(), quarter * (3 + 3 + 5 + 7));
     }
 }
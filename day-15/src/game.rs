@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+/// Marks numbers which weren't spoken yet in the last-seen table
+const NEVER: u32 = 0;
+
+/// Turn at which every number was spoken the last time, dense for numbers below `size`
+#[derive(Debug)]
+struct LastSeen {
+    table: Vec<u32>,
+    /// Starting numbers too large for the table, there are only a few of them
+    large: HashMap<u32, u32>,
+}
+
+impl LastSeen {
+    fn new(size: u32) -> Self {
+        Self {
+            table: vec![NEVER; size as usize],
+            large: HashMap::new(),
+        }
+    }
+
+    fn get_mut(&mut self, number: u32) -> &mut u32 {
+        match self.table.get_mut(number as usize) {
+            Some(turn) => turn,
+            None => self.large.entry(number).or_insert(NEVER),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct MemoryGame {
+    starting_numbers: Vec<u32>,
+}
+
+impl From<Vec<u32>> for MemoryGame {
+    fn from(starting_numbers: Vec<u32>) -> Self {
+        Self { starting_numbers }
+    }
+}
+
+impl MemoryGame {
+    /// Number spoken at the 1-based `turn`
+    pub(crate) fn spoken_at(&self, turn: u32) -> Option<u32> {
+        let (&last_starting, starting) = self.starting_numbers.split_last()?;
+
+        if turn == 0 {
+            return None;
+        }
+        if let Some(&number) = self.starting_numbers.get(turn as usize - 1) {
+            return Some(number);
+        }
+
+        // every spoken number is either a starting one or an age, which is less than `turn`,
+        // so only large starting numbers don't fit into the table;
+        // the number spoken on the previous turn is recorded only when the next one is chosen
+        let mut last_seen = LastSeen::new(turn);
+        for (idx, &number) in starting.iter().enumerate() {
+            *last_seen.get_mut(number) = idx as u32 + 1;
+        }
+
+        let mut spoken = last_starting;
+        for previous_turn in self.starting_numbers.len() as u32..turn {
+            let seen = std::mem::replace(last_seen.get_mut(spoken), previous_turn);
+
+            spoken = match seen {
+                NEVER => 0,
+                seen => previous_turn - seen,
+            };
+        }
+
+        Some(spoken)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let game = MemoryGame::from(vec![0, 3, 6]);
+
+        let spoken = (1..=10)
+            .map(|turn| game.spoken_at(turn).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(spoken, vec![0, 3, 6, 0, 3, 3, 1, 0, 4, 0]);
+        assert_eq!(game.spoken_at(2020), Some(436));
+        assert_eq!(game.spoken_at(0), None);
+    }
+
+    #[test]
+    fn test_2020th_turn() {
+        macro_rules! assert_spoken_eq {
+            ($starting: expr, $number: expr) => {
+                assert_eq!(
+                    MemoryGame::from($starting.to_vec()).spoken_at(2020),
+                    Some($number)
+                );
+            };
+        }
+
+        assert_spoken_eq!([1, 3, 2], 1);
+        assert_spoken_eq!([2, 1, 3], 10);
+        assert_spoken_eq!([1, 2, 3], 27);
+        assert_spoken_eq!([2, 3, 1], 78);
+        assert_spoken_eq!([3, 2, 1], 438);
+        assert_spoken_eq!([3, 1, 2], 1836);
+    }
+
+    #[test]
+    fn test_large_starting_numbers() {
+        let game = MemoryGame::from(vec![u32::MAX, 7, u32::MAX]);
+
+        let spoken = (1..=8)
+            .map(|turn| game.spoken_at(turn).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(spoken, vec![u32::MAX, 7, u32::MAX, 2, 0, 0, 1, 0]);
+        assert_eq!(
+            MemoryGame::from(vec![u32::MAX]).spoken_at(1),
+            Some(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn test_30000000th_turn() {
+        assert_eq!(
+            MemoryGame::from(vec![0, 3, 6]).spoken_at(30_000_000),
+            Some(175594)
+        );
+    }
+}
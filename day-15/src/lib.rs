@@ -0,0 +1,124 @@
+use std::{error::Error, num::ParseIntError, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{Located, ParseError, SolveError, Solved, TypedSolver};
+use displaydoc::Display;
+use thiserror::Error;
+
+mod game;
+
+use game::MemoryGame;
+
+const PART_ONE_TURN: u32 = 2020;
+const PART_TWO_TURN: u32 = 30_000_000;
+
+#[derive(Debug)]
+pub struct Solution {
+    game: MemoryGame,
+}
+
+#[derive(Debug, Display, Error)]
+/// Error while parsing starting numbers
+pub enum StartingNumbersParseError {
+    /// No starting numbers given
+    Empty,
+    /// Invalid starting number {number:?}: {source}
+    InvalidNumber {
+        number: String,
+        source: ParseIntError,
+        offset: usize,
+    },
+}
+
+impl Located for StartingNumbersParseError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::Empty => None,
+            Self::InvalidNumber { offset, .. } => Some(offset),
+        }
+    }
+}
+
+fn parse_starting_numbers(line: &str) -> Result<Vec<u32>, StartingNumbersParseError> {
+    if line.is_empty() {
+        return Err(StartingNumbersParseError::Empty);
+    }
+
+    let mut offset = 0;
+
+    line.split(',')
+        .map(|number| {
+            let parsed =
+                number
+                    .parse()
+                    .map_err(|source| StartingNumbersParseError::InvalidNumber {
+                        number: number.to_owned(),
+                        source,
+                        offset,
+                    });
+            offset += number.chars().count() + 1;
+
+            parsed
+        })
+        .collect()
+}
+
+impl FromStr for Solution {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input_data: &str) -> Result<Self, Self::Err> {
+        let line = input_data.lines().next().unwrap_or_default();
+        let starting_numbers =
+            parse_starting_numbers(line).map_err(|e| ParseError::located(1, line, e))?;
+
+        Ok(Self {
+            game: starting_numbers.into(),
+        })
+    }
+}
+
+impl Solver for Solution {
+    fn day_number() -> u32 {
+        15
+    }
+
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        let turn = match part {
+            Part::One => PART_ONE_TURN,
+            Part::Two => PART_TWO_TURN,
+        };
+
+        match self.game.spoken_at(turn) {
+            Some(number) => Ok(Solved::new(
+                number,
+                format!("{}th number spoken is {}", turn, number),
+            )),
+            None => Err(SolveError::no_solution("nobody started the game")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "0,3,-6".parse::<Solution>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 5: Invalid starting number \"-6\": invalid digit found in string (in \"0,3,-6\")"
+        );
+
+        let e = "".parse::<Solution>().unwrap_err();
+
+        assert_eq!(e.to_string(), "line 1: No starting numbers given (in \"\")");
+    }
+}
@@ -0,0 +1,9 @@
+use std::error::Error;
+
+use advent_utils::Solver;
+
+use day_15::Solution;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    Solution::solve_env_config()
+}
@@ -0,0 +1,335 @@
+use std::{collections::BTreeMap, error::Error, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{assign, ParseError, SolveError, Solved, TypedSolver};
+use displaydoc::Display;
+use thiserror::Error;
+
+mod rule;
+mod ticket;
+
+pub use common::AssignmentError;
+use rule::Rule;
+use ticket::Ticket;
+
+const YOUR_TICKET_HEADER: &str = "your ticket:";
+const NEARBY_TICKETS_HEADER: &str = "nearby tickets:";
+const DEPARTURE_PREFIX: &str = "departure";
+
+#[derive(Debug)]
+pub struct Solution {
+    rules: Vec<Rule>,
+    your_ticket: Ticket,
+    nearby_tickets: Vec<Ticket>,
+}
+
+#[derive(Debug, Display, Error)]
+/// Error while parsing notes
+enum NotesParseError {
+    /// Expected {0:?}
+    MissingHeader(&'static str),
+    /// Your ticket not specified
+    NoTicket,
+    /// Ticket has {got} values, but there are {expected} rules
+    FieldCountMismatch { expected: usize, got: usize },
+}
+
+/// Skips empty lines, expecting the next one to be `header`
+fn expect_header<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    header: &'static str,
+    eof: usize,
+) -> Result<(), ParseError> {
+    match lines.find(|(_, line)| !line.is_empty()) {
+        Some((_, line)) if line == header => Ok(()),
+        Some((number, line)) => Err(ParseError::new(
+            number,
+            line,
+            NotesParseError::MissingHeader(header),
+        )),
+        None => Err(ParseError::new(
+            eof,
+            "",
+            NotesParseError::MissingHeader(header),
+        )),
+    }
+}
+
+impl FromStr for Solution {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input_data: &str) -> Result<Self, Self::Err> {
+        let eof = input_data.lines().count() + 1;
+        let mut lines = input_data
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| (idx + 1, line));
+
+        let mut rules = Vec::new();
+        for (number, line) in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+
+            rules.push(
+                line.parse::<Rule>()
+                    .map_err(|e| ParseError::located(number, line, e))?,
+            );
+        }
+
+        let parse_ticket = |(number, line): (usize, &str)| {
+            let ticket = line
+                .parse::<Ticket>()
+                .map_err(|e| ParseError::located(number, line, e))?;
+
+            if ticket.values().len() != rules.len() {
+                return Err(ParseError::new(
+                    number,
+                    line,
+                    NotesParseError::FieldCountMismatch {
+                        expected: rules.len(),
+                        got: ticket.values().len(),
+                    },
+                ));
+            }
+
+            Ok(ticket)
+        };
+
+        expect_header(&mut lines, YOUR_TICKET_HEADER, eof)?;
+        let your_ticket = match lines.next() {
+            Some((number, "")) => {
+                return Err(ParseError::new(number, "", NotesParseError::NoTicket).into())
+            }
+            None => return Err(ParseError::new(eof, "", NotesParseError::NoTicket).into()),
+            Some(line) => parse_ticket(line)?,
+        };
+
+        expect_header(&mut lines, NEARBY_TICKETS_HEADER, eof)?;
+        let nearby_tickets = lines
+            .filter(|(_, line)| !line.is_empty())
+            .map(parse_ticket)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            rules,
+            your_ticket,
+            nearby_tickets,
+        })
+    }
+}
+
+impl Solution {
+    fn is_valid_value(&self, value: u64) -> bool {
+        self.rules.iter().any(|rule| rule.is_valid(value))
+    }
+
+    fn error_rate(&self) -> u64 {
+        self.nearby_tickets
+            .iter()
+            .flat_map(Ticket::values)
+            .filter(|&&value| !self.is_valid_value(value))
+            .sum()
+    }
+
+    /// Column of every field, deduced from your ticket and valid nearby ones
+    pub fn field_assignment(&self) -> Result<BTreeMap<&str, usize>, AssignmentError> {
+        let valid_tickets = self
+            .nearby_tickets
+            .iter()
+            .filter(|ticket| ticket.values().iter().all(|&v| self.is_valid_value(v)))
+            .chain(Some(&self.your_ticket))
+            .collect::<Vec<_>>();
+
+        let candidates = self.rules.iter().map(|rule| {
+            let columns = (0..self.rules.len())
+                .filter(|&column| {
+                    valid_tickets
+                        .iter()
+                        .all(|ticket| rule.is_valid(ticket.values()[column]))
+                })
+                .collect();
+
+            (rule.field(), columns)
+        });
+
+        assign(candidates)
+    }
+
+    /// Your ticket's values by field
+    pub fn your_ticket_fields(&self) -> Result<BTreeMap<&str, u64>, AssignmentError> {
+        Ok(self
+            .field_assignment()?
+            .into_iter()
+            .map(|(field, column)| (field, self.your_ticket.values()[column]))
+            .collect())
+    }
+}
+
+impl Solver for Solution {
+    fn day_number() -> u32 {
+        16
+    }
+
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        match part {
+            Part::One => {
+                let error_rate = self.error_rate();
+
+                Ok(Solved::new(
+                    error_rate,
+                    format!("ticket scanning error rate is {}", error_rate),
+                ))
+            }
+            Part::Two => {
+                let fields = self
+                    .your_ticket_fields()
+                    .map_err(|e| SolveError::no_solution(e.to_string()))?;
+
+                let departure_fields = fields
+                    .iter()
+                    .filter(|(field, _)| field.starts_with(DEPARTURE_PREFIX))
+                    .map(|(_, &value)| value)
+                    .collect::<Vec<_>>();
+
+                if departure_fields.is_empty() {
+                    return Err(SolveError::no_solution("there are no departure fields"));
+                }
+
+                let product = departure_fields
+                    .iter()
+                    .try_fold(1u64, |product, &value| product.checked_mul(value))
+                    .ok_or_else(|| {
+                        SolveError::no_solution("product of departure fields overflows")
+                    })?;
+
+                Ok(Solved::new(
+                    product,
+                    format!("product of departure fields is {}", product),
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+
+    #[test]
+    fn test_error_rate() {
+        let solution: Solution = indoc!(
+            "class: 1-3 or 5-7
+            row: 6-11 or 33-44
+            seat: 13-40 or 45-50
+
+            your ticket:
+            7,1,14
+
+            nearby tickets:
+            7,3,47
+            40,4,50
+            55,2,20
+            38,6,12"
+        )
+        .parse()
+        .unwrap();
+
+        assert_eq!(solution.error_rate(), 71);
+    }
+
+    #[test]
+    fn test_field_assignment() {
+        let solution: Solution = indoc!(
+            "class: 0-1 or 4-19
+            row: 0-5 or 8-19
+            seat: 0-13 or 16-19
+
+            your ticket:
+            11,12,13
+
+            nearby tickets:
+            3,9,18
+            15,1,5
+            5,14,9"
+        )
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            solution.field_assignment(),
+            Ok(BTreeMap::from([("class", 1), ("row", 0), ("seat", 2)]))
+        );
+        assert_eq!(
+            solution.your_ticket_fields(),
+            Ok(BTreeMap::from([("class", 12), ("row", 11), ("seat", 13)]))
+        );
+    }
+
+    #[test]
+    fn test_product_overflow() {
+        let solution: Solution = indoc!(
+            "departure location: 0-1 or 4294967296-4294967296
+            departure station: 2-3 or 4294967296-4294967296
+
+            your ticket:
+            4294967296,4294967296
+
+            nearby tickets:
+            0,4294967296"
+        )
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            solution.solve_typed(Part::Two),
+            Err(SolveError::no_solution(
+                "product of departure fields overflows"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = indoc!(
+            "class: 1-3 or 5-7
+            row: 6-11 or 33-44
+
+            your ticket:
+            7,1
+
+            nearby tickets:
+            7,3
+            40,x"
+        )
+        .parse::<Solution>()
+        .unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 9, column 4: Invalid ticket value \"x\": invalid digit found in string (in \"40,x\")"
+        );
+
+        let e = indoc!(
+            "class: 1-3 or 5-7
+
+            nearby tickets:
+            7"
+        )
+        .parse::<Solution>()
+        .unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 3: Expected \"your ticket:\" (in \"nearby tickets:\")"
+        );
+    }
+}
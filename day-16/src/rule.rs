@@ -0,0 +1,147 @@
+use std::{num::ParseIntError, ops::RangeInclusive, str::FromStr};
+
+use common::Located;
+use displaydoc::Display;
+use thiserror::Error;
+
+/// Example: `6-11`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Range(RangeInclusive<u64>);
+
+/// Example: `departure location: 6-11 or 33-44`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Rule {
+    field: String,
+    ranges: Vec<Range>,
+}
+
+impl Rule {
+    pub(crate) fn field(&self) -> &str {
+        &self.field
+    }
+
+    pub(crate) fn is_valid(&self, value: u64) -> bool {
+        self.ranges.iter().any(|range| range.0.contains(&value))
+    }
+}
+
+/// Failed to parse range
+#[derive(Debug, Display, Error)]
+pub(crate) enum RangeParseError {
+    /// Failed to parse number: {0}
+    NumberParseError(#[from] ParseIntError),
+    /// Range start {start} is greater than its end {end}
+    Reversed { start: u64, end: u64 },
+    /// Unknown range format
+    UnknownFormat,
+}
+
+impl FromStr for Range {
+    type Err = RangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s.split_once('-').ok_or(RangeParseError::UnknownFormat)?;
+        let (start, end) = (start.parse()?, end.parse()?);
+
+        if start > end {
+            return Err(RangeParseError::Reversed { start, end });
+        }
+
+        Ok(Self(start..=end))
+    }
+}
+
+/// Failed to parse rule
+#[derive(Debug, Display, Error)]
+pub(crate) enum RuleParseError {
+    /// Invalid range {range:?}: {source}
+    RangeParseError {
+        range: String,
+        source: RangeParseError,
+        offset: usize,
+    },
+    /// Rule has no ranges
+    NoRanges,
+    /// Unknown rule format
+    UnknownFormat,
+}
+
+impl Located for RuleParseError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::RangeParseError { offset, .. } => Some(offset),
+            _ => None,
+        }
+    }
+}
+
+const RANGES_SEPARATOR: &str = " or ";
+
+impl FromStr for Rule {
+    type Err = RuleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (field, ranges) = s.split_once(": ").ok_or(RuleParseError::UnknownFormat)?;
+
+        if ranges.is_empty() {
+            return Err(RuleParseError::NoRanges);
+        }
+
+        let mut offset = field.chars().count() + 2;
+
+        let ranges = ranges
+            .split(RANGES_SEPARATOR)
+            .map(|range| {
+                let parsed = range
+                    .parse()
+                    .map_err(|source| RuleParseError::RangeParseError {
+                        range: range.to_owned(),
+                        source,
+                        offset,
+                    });
+                offset += range.chars().count() + RANGES_SEPARATOR.len();
+
+                parsed
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            field: field.to_owned(),
+            ranges,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_parse() {
+        let rule: Rule = "departure location: 6-11 or 33-44".parse().unwrap();
+
+        assert_eq!(
+            rule,
+            Rule {
+                field: "departure location".to_owned(),
+                ranges: vec![Range(6..=11), Range(33..=44)],
+            }
+        );
+        assert!(rule.is_valid(11));
+        assert!(!rule.is_valid(12));
+        assert!(rule.is_valid(33));
+
+        let e = "row: 6-11 or 44-33".parse::<Rule>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "Invalid range \"44-33\": Range start 44 is greater than its end 33"
+        );
+        assert_eq!(e.offset(), Some(13));
+
+        assert!(matches!(
+            "row 6-11".parse::<Rule>(),
+            Err(RuleParseError::UnknownFormat)
+        ));
+    }
+}
@@ -0,0 +1,58 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use common::Located;
+use displaydoc::Display;
+use thiserror::Error;
+
+/// Example: `7,1,14`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Ticket(Vec<u64>);
+
+impl Ticket {
+    pub(crate) fn values(&self) -> &[u64] {
+        &self.0
+    }
+}
+
+/// Failed to parse ticket
+#[derive(Debug, Display, Error)]
+pub(crate) enum TicketParseError {
+    /// Invalid ticket value {value:?}: {source}
+    InvalidValue {
+        value: String,
+        source: ParseIntError,
+        offset: usize,
+    },
+}
+
+impl Located for TicketParseError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::InvalidValue { offset, .. } => Some(offset),
+        }
+    }
+}
+
+impl FromStr for Ticket {
+    type Err = TicketParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut offset = 0;
+
+        s.split(',')
+            .map(|value| {
+                let parsed = value
+                    .parse()
+                    .map_err(|source| TicketParseError::InvalidValue {
+                        value: value.to_owned(),
+                        source,
+                        offset,
+                    });
+                offset += value.chars().count() + 1;
+
+                parsed
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
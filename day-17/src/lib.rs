@@ -0,0 +1,64 @@
+use std::{error::Error, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{SolveError, Solved, TypedSolver};
+
+mod pocket;
+
+use pocket::{Pocket, Slice};
+
+const BOOT_CYCLES: usize = 6;
+
+#[derive(Debug)]
+pub struct Solution {
+    slice: Slice,
+}
+
+impl FromStr for Solution {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input_data: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            slice: input_data.parse()?,
+        })
+    }
+}
+
+impl Solution {
+    /// Number of active cubes after `cycles` in a pocket of `dimensions` (at least 2)
+    pub fn active_cubes(&self, dimensions: usize, cycles: usize) -> usize {
+        let mut pocket = Pocket::new(&self.slice, dimensions);
+        (0..cycles).for_each(|_| pocket.step());
+
+        pocket.active_count()
+    }
+}
+
+impl Solver for Solution {
+    fn day_number() -> u32 {
+        17
+    }
+
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        let dimensions = match part {
+            Part::One => 3,
+            Part::Two => 4,
+        };
+
+        let active = self.active_cubes(dimensions, BOOT_CYCLES);
+
+        Ok(Solved::new(
+            active,
+            format!(
+                "{} cubes are active in {}D after the boot process",
+                active, dimensions
+            ),
+        ))
+    }
+}
@@ -0,0 +1,355 @@
+use std::{fmt, str::FromStr};
+
+use common::ParseError;
+use displaydoc::Display;
+use thiserror::Error;
+
+const ACTIVE: char = '#';
+const INACTIVE: char = '.';
+/// Names of the axes, the puzzle doesn't go beyond `w`
+const AXES: [&str; 4] = ["x", "y", "z", "w"];
+
+/// Initial 2D slice of the pocket dimension
+#[derive(Debug, Clone)]
+pub(crate) struct Slice {
+    width: usize,
+    cells: Vec<bool>,
+}
+
+#[derive(Debug, Display, Error)]
+pub(crate) enum SliceParseError {
+    /// Invalid cube state: {0}
+    InvalidState(char),
+    /// Inconsistent slice width
+    InconsistentWidth,
+    /// Empty slice
+    EmptySlice,
+}
+
+impl FromStr for Slice {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut width = None;
+        let mut cells = Vec::new();
+
+        for (idx, line) in s.lines().enumerate() {
+            let line_error = |e| ParseError::new(idx + 1, line, e);
+
+            if *width.get_or_insert(line.len()) != line.len() {
+                return Err(line_error(SliceParseError::InconsistentWidth));
+            }
+
+            for (offset, c) in line.chars().enumerate() {
+                cells.push(match c {
+                    ACTIVE => true,
+                    INACTIVE => false,
+                    other => {
+                        return Err(
+                            line_error(SliceParseError::InvalidState(other)).at_offset(offset)
+                        )
+                    }
+                });
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self { width, cells }),
+            _ => Err(ParseError::new(1, "", SliceParseError::EmptySlice)),
+        }
+    }
+}
+
+/// Dense grid of cubes in any number of dimensions, covering just the active region.
+///
+/// The first dimension is the fastest changing one in `cells`.
+#[derive(Debug, Clone)]
+pub(crate) struct Pocket {
+    shape: Vec<usize>,
+    /// Coordinates of the first cell, the initial slice starts at the origin
+    origin: Vec<isize>,
+    cells: Vec<bool>,
+}
+
+fn strides(shape: &[usize]) -> Vec<usize> {
+    shape
+        .iter()
+        .scan(1, |stride, &size| {
+            let current = *stride;
+            *stride *= size;
+
+            Some(current)
+        })
+        .collect()
+}
+
+fn coordinates(mut idx: usize, shape: &[usize]) -> impl Iterator<Item = usize> + '_ {
+    shape.iter().map(move |&size| {
+        let coordinate = idx % size;
+        idx /= size;
+
+        coordinate
+    })
+}
+
+/// Linear offsets of all `3^n - 1` neighbours of a cell
+fn neighbour_offsets(strides: &[usize]) -> Vec<isize> {
+    let count = 3usize.pow(strides.len() as u32);
+
+    (0..count)
+        .map(|mut combination| {
+            strides
+                .iter()
+                .map(|&stride| {
+                    let delta = (combination % 3) as isize - 1;
+                    combination /= 3;
+
+                    delta * stride as isize
+                })
+                .sum()
+        })
+        .filter(|&offset| offset != 0)
+        .collect()
+}
+
+impl Pocket {
+    /// Embeds the `slice` into a pocket of `dimensions` (at least 2) dimensions
+    pub(crate) fn new(slice: &Slice, dimensions: usize) -> Self {
+        assert!(dimensions >= 2, "slice doesn't fit into {}D", dimensions);
+
+        let mut shape = vec![1; dimensions];
+        shape[0] = slice.width;
+        shape[1] = slice.cells.len() / slice.width;
+
+        Self {
+            shape,
+            origin: vec![0; dimensions],
+            cells: slice.cells.clone(),
+        }
+    }
+
+    pub(crate) fn active_count(&self) -> usize {
+        self.cells.iter().filter(|&&active| active).count()
+    }
+
+    /// Runs a single boot cycle
+    pub(crate) fn step(&mut self) {
+        // active region may grow by at most one cube on each side
+        let grown_shape = self.shape.iter().map(|size| size + 2).collect::<Vec<_>>();
+        let grown_strides = strides(&grown_shape);
+        let offsets = neighbour_offsets(&grown_strides);
+
+        let grown_len = grown_shape.iter().product();
+        let mut was_active = vec![false; grown_len];
+        // only counts up to 3 matter, so saturating is fine
+        let mut neighbours = vec![0u8; grown_len];
+
+        for (idx, _) in self.cells.iter().enumerate().filter(|(_, &active)| active) {
+            let grown_idx = coordinates(idx, &self.shape)
+                .zip(&grown_strides)
+                .map(|(coordinate, stride)| (coordinate + 1) * stride)
+                .sum::<usize>();
+
+            was_active[grown_idx] = true;
+            for &offset in &offsets {
+                let neighbour = &mut neighbours[grown_idx.wrapping_add_signed(offset)];
+                *neighbour = neighbour.saturating_add(1);
+            }
+        }
+
+        self.shape = grown_shape;
+        self.origin.iter_mut().for_each(|origin| *origin -= 1);
+        self.cells = was_active
+            .into_iter()
+            .zip(neighbours)
+            .map(|(active, neighbours)| neighbours == 3 || (active && neighbours == 2))
+            .collect();
+
+        self.trim();
+    }
+
+    /// Shrinks the grid to the bounding box of active cubes
+    fn trim(&mut self) {
+        let dimensions = self.shape.len();
+        let mut min = vec![usize::MAX; dimensions];
+        let mut max = vec![0; dimensions];
+
+        for (idx, _) in self.cells.iter().enumerate().filter(|(_, &active)| active) {
+            for (dimension, coordinate) in coordinates(idx, &self.shape).enumerate() {
+                min[dimension] = min[dimension].min(coordinate);
+                max[dimension] = max[dimension].max(coordinate);
+            }
+        }
+
+        if min[0] > max[0] {
+            self.shape = vec![0; dimensions];
+            self.cells.clear();
+
+            return;
+        }
+
+        let trimmed_shape = min
+            .iter()
+            .zip(&max)
+            .map(|(min, max)| max - min + 1)
+            .collect::<Vec<_>>();
+        let strides = strides(&self.shape);
+        let origin = min
+            .iter()
+            .zip(&strides)
+            .map(|(min, stride)| min * stride)
+            .sum::<usize>();
+
+        self.cells = (0..trimmed_shape.iter().product())
+            .map(|idx| {
+                let offset = coordinates(idx, &trimmed_shape)
+                    .zip(&strides)
+                    .map(|(coordinate, stride)| coordinate * stride)
+                    .sum::<usize>();
+
+                self.cells[origin + offset]
+            })
+            .collect();
+        self.shape = trimmed_shape;
+        self.origin
+            .iter_mut()
+            .zip(&min)
+            .for_each(|(origin, &min)| *origin += min as isize);
+    }
+}
+
+/// Prints 2D slices of the pocket headed by their coordinates, like the puzzle does
+impl fmt::Display for Pocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let slice_len = self.shape.iter().take(2).product::<usize>();
+        if slice_len == 0 {
+            return Ok(());
+        }
+
+        for (slice_idx, slice) in self.cells.chunks(slice_len).enumerate() {
+            if slice_idx > 0 {
+                writeln!(f)?;
+            }
+
+            // a 2D pocket is a single slice, there's nothing to tell them apart
+            if self.shape.len() > 2 {
+                let position = coordinates(slice_idx, &self.shape[2..])
+                    .zip(&self.origin[2..])
+                    .enumerate()
+                    .map(|(idx, (coordinate, origin))| {
+                        let dimension = idx + 2;
+                        let value = coordinate as isize + origin;
+
+                        match AXES.get(dimension) {
+                            Some(axis) => format!("{}={}", axis, value),
+                            None => format!("axis {}={}", dimension, value),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                writeln!(f, "{}", position.join(", "))?;
+            }
+
+            for row in slice.chunks(self.shape[0]) {
+                for &active in row {
+                    write!(f, "{}", if active { ACTIVE } else { INACTIVE })?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc!(
+        ".#.
+        ..#
+        ###"
+    );
+
+    #[test]
+    fn test_examples() {
+        let slice: Slice = EXAMPLE.parse().unwrap();
+
+        macro_rules! assert_active_eq {
+            ($dimensions: expr, $active: expr) => {
+                let mut pocket = Pocket::new(&slice, $dimensions);
+                (0..6).for_each(|_| pocket.step());
+
+                assert_eq!(pocket.active_count(), $active);
+            };
+        }
+
+        assert_active_eq!(2, 5);
+        assert_active_eq!(3, 112);
+        assert_active_eq!(4, 848);
+    }
+
+    #[test]
+    fn test_first_cycle() {
+        let mut pocket = Pocket::new(&EXAMPLE.parse().unwrap(), 3);
+        pocket.step();
+
+        assert_eq!(pocket.active_count(), 11);
+        assert_eq!(
+            pocket.to_string(),
+            indoc!(
+                "z=-1
+                #..
+                ..#
+                .#.
+
+                z=0
+                #.#
+                .##
+                .#.
+
+                z=1
+                #..
+                ..#
+                .#.
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn test_slice_headers() {
+        let slice = EXAMPLE.parse().unwrap();
+
+        let mut pocket = Pocket::new(&slice, 2);
+        pocket.step();
+
+        assert_eq!(
+            pocket.to_string(),
+            indoc!(
+                "#.#
+                .##
+                .#.
+                "
+            )
+        );
+
+        let mut pocket = Pocket::new(&slice, 4);
+        pocket.step();
+
+        assert!(pocket.to_string().starts_with("z=-1, w=-1\n"));
+        assert!(pocket.to_string().contains("\nz=1, w=0\n"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = ".#.\n.o#".parse::<Slice>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 2: Invalid cube state: o (in \".o#\")"
+        );
+    }
+}
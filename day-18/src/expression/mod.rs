@@ -0,0 +1,161 @@
+mod parser;
+mod token;
+
+use std::{fmt, num::ParseIntError};
+
+use common::Located;
+use displaydoc::Display;
+use thiserror::Error;
+
+pub use parser::parse;
+pub use token::Token;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Operator {
+    Add,
+    Multiply,
+}
+
+impl Operator {
+    fn apply(self, lhs: u64, rhs: u64) -> Option<u64> {
+        match self {
+            Self::Add => lhs.checked_add(rhs),
+            Self::Multiply => lhs.checked_mul(rhs),
+        }
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Add => write!(f, "+"),
+            Self::Multiply => write!(f, "*"),
+        }
+    }
+}
+
+/// Binding power of operators, higher binds tighter
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Precedence {
+    pub add: u8,
+    pub multiply: u8,
+}
+
+impl Precedence {
+    /// Operators are evaluated left to right
+    pub const EQUAL: Self = Self {
+        add: 1,
+        multiply: 1,
+    };
+    /// Addition is evaluated before multiplication
+    pub const ADDITION_FIRST: Self = Self {
+        add: 2,
+        multiply: 1,
+    };
+
+    fn of(&self, operator: Operator) -> u8 {
+        match operator {
+            Operator::Add => self.add,
+            Operator::Multiply => self.multiply,
+        }
+    }
+}
+
+/// Parsed expression, printed with every operation parenthesized
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Expr {
+    Number(u64),
+    Binary {
+        operator: Operator,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+    },
+}
+
+impl Expr {
+    /// Value of the expression, `None` if it overflows
+    pub fn eval(&self) -> Option<u64> {
+        match self {
+            Self::Number(value) => Some(*value),
+            Self::Binary { operator, lhs, rhs } => operator.apply(lhs.eval()?, rhs.eval()?),
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "{}", value),
+            Self::Binary { operator, lhs, rhs } => write!(f, "({} {} {})", lhs, operator, rhs),
+        }
+    }
+}
+
+#[derive(Debug, Display, Error)]
+pub enum ExpressionParseError {
+    /// Unexpected char {chr:?}
+    UnexpectedChar { chr: char, offset: usize },
+    /// Invalid number {number:?}: {source}
+    InvalidNumber {
+        number: String,
+        source: ParseIntError,
+        offset: usize,
+    },
+    /// Unexpected token `{token}`
+    UnexpectedToken { token: Token, offset: usize },
+    /// Unexpected end of expression
+    UnexpectedEnd { offset: usize },
+    /// Parenthesis is never closed
+    UnclosedParen { offset: usize },
+}
+
+impl Located for ExpressionParseError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::UnexpectedChar { offset, .. }
+            | Self::InvalidNumber { offset, .. }
+            | Self::UnexpectedToken { offset, .. }
+            | Self::UnexpectedEnd { offset }
+            | Self::UnclosedParen { offset } => Some(offset),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        macro_rules! assert_eval_eq {
+            ($expr: expr, $equal: expr, $addition_first: expr) => {
+                assert_eq!(
+                    parse($expr, Precedence::EQUAL).unwrap().eval(),
+                    Some($equal)
+                );
+                assert_eq!(
+                    parse($expr, Precedence::ADDITION_FIRST).unwrap().eval(),
+                    Some($addition_first)
+                );
+            };
+        }
+
+        assert_eval_eq!("1 + 2 * 3 + 4 * 5 + 6", 71, 231);
+        assert_eval_eq!("1 + (2 * 3) + (4 * (5 + 6))", 51, 51);
+        assert_eval_eq!("2 * 3 + (4 * 5)", 26, 46);
+        assert_eval_eq!("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437, 1445);
+        assert_eval_eq!("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240, 669060);
+        assert_eval_eq!(
+            "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
+            13632,
+            23340
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let expr = parse("18446744073709551615 + 1", Precedence::EQUAL).unwrap();
+
+        assert_eq!(expr.eval(), None);
+    }
+}
@@ -0,0 +1,134 @@
+use std::{iter::Peekable, vec::IntoIter};
+
+use super::{
+    token::{tokenize, Spanned, Token},
+    Expr, ExpressionParseError, Precedence,
+};
+
+/// Precedence climbing parser, all operators are left-associative
+struct Parser {
+    tokens: Peekable<IntoIter<Spanned>>,
+    precedence: Precedence,
+    end: usize,
+}
+
+impl Parser {
+    fn expression(&mut self, min_precedence: u8) -> Result<Expr, ExpressionParseError> {
+        let mut lhs = self.operand()?;
+
+        while let Some(&(Token::Operator(operator), _)) = self.tokens.peek() {
+            let precedence = self.precedence.of(operator);
+            if precedence < min_precedence {
+                break;
+            }
+
+            self.tokens.next();
+            // nothing binds tighter than the highest precedence, so its rhs is a single operand
+            let rhs = match precedence.checked_add(1) {
+                Some(min_precedence) => self.expression(min_precedence)?,
+                None => self.operand()?,
+            };
+
+            lhs = Expr::Binary {
+                operator,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            };
+        }
+
+        Ok(lhs)
+    }
+
+    fn operand(&mut self) -> Result<Expr, ExpressionParseError> {
+        match self.tokens.next() {
+            Some((Token::Number(value), _)) => Ok(Expr::Number(value)),
+            Some((Token::OpenParen, open_offset)) => {
+                let inner = self.expression(0)?;
+
+                match self.tokens.next() {
+                    Some((Token::CloseParen, _)) => Ok(inner),
+                    Some((token, offset)) => {
+                        Err(ExpressionParseError::UnexpectedToken { token, offset })
+                    }
+                    None => Err(ExpressionParseError::UnclosedParen {
+                        offset: open_offset,
+                    }),
+                }
+            }
+            Some((token, offset)) => Err(ExpressionParseError::UnexpectedToken { token, offset }),
+            None => Err(ExpressionParseError::UnexpectedEnd { offset: self.end }),
+        }
+    }
+}
+
+/// Parses `s` into an AST, binding operators according to `precedence`
+pub fn parse(s: &str, precedence: Precedence) -> Result<Expr, ExpressionParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(s)?.into_iter().peekable(),
+        precedence,
+        end: s.chars().count(),
+    };
+
+    let expr = parser.expression(0)?;
+
+    match parser.tokens.next() {
+        Some((token, offset)) => Err(ExpressionParseError::UnexpectedToken { token, offset }),
+        None => Ok(expr),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precedence() {
+        let expr = "1 + 2 * 3 + 4";
+
+        assert_eq!(
+            parse(expr, Precedence::EQUAL).unwrap().to_string(),
+            "(((1 + 2) * 3) + 4)"
+        );
+        assert_eq!(
+            parse(expr, Precedence::ADDITION_FIRST).unwrap().to_string(),
+            "((1 + 2) * (3 + 4))"
+        );
+        assert_eq!(
+            parse("2 * (3 + 4) * 5", Precedence::EQUAL)
+                .unwrap()
+                .to_string(),
+            "((2 * (3 + 4)) * 5)"
+        );
+
+        let highest = Precedence {
+            add: u8::MAX,
+            multiply: u8::MAX - 1,
+        };
+
+        assert_eq!(
+            parse("1 + 2 + 3 * 4 + 5", highest).unwrap().to_string(),
+            "(((1 + 2) + 3) * (4 + 5))"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        macro_rules! assert_error_eq {
+            ($expr: expr, $message: expr, $offset: expr) => {
+                let e = parse($expr, Precedence::EQUAL).unwrap_err();
+
+                assert_eq!(e.to_string(), $message);
+                assert_eq!(common::Located::offset(&e), Some($offset));
+            };
+        }
+
+        assert_error_eq!("1 + * 2", "Unexpected token `*`", 4);
+        assert_error_eq!("(1 + 2", "Parenthesis is never closed", 0);
+        assert_error_eq!("1 + 2)", "Unexpected token `)`", 5);
+        assert_error_eq!("1 2", "Unexpected token `2`", 2);
+        assert_error_eq!("(1 + 2 3)", "Unexpected token `3`", 7);
+        assert_error_eq!("1 +", "Unexpected end of expression", 3);
+        assert_error_eq!("()", "Unexpected token `)`", 1);
+        assert_error_eq!("2 ^ 3", "Unexpected char '^'", 2);
+    }
+}
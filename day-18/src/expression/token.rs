@@ -0,0 +1,91 @@
+use std::fmt;
+
+use super::{ExpressionParseError, Operator};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Token {
+    Number(u64),
+    Operator(Operator),
+    OpenParen,
+    CloseParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "{}", value),
+            Self::Operator(operator) => write!(f, "{}", operator),
+            Self::OpenParen => write!(f, "("),
+            Self::CloseParen => write!(f, ")"),
+        }
+    }
+}
+
+/// Token along with the char offset it starts at
+pub(super) type Spanned = (Token, usize);
+
+pub(super) fn tokenize(s: &str) -> Result<Vec<Spanned>, ExpressionParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = s.chars().enumerate().peekable();
+
+    while let Some((offset, chr)) = chars.next() {
+        let token = match chr {
+            ' ' => continue,
+            '+' => Token::Operator(Operator::Add),
+            '*' => Token::Operator(Operator::Multiply),
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            '0'..='9' => {
+                let mut digits = String::from(chr);
+                while let Some((_, digit)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                    digits.push(digit);
+                }
+
+                let value =
+                    digits
+                        .parse()
+                        .map_err(|source| ExpressionParseError::InvalidNumber {
+                            number: digits.clone(),
+                            source,
+                            offset,
+                        })?;
+
+                Token::Number(value)
+            }
+            chr => return Err(ExpressionParseError::UnexpectedChar { chr, offset }),
+        };
+
+        tokens.push((token, offset));
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("12 * (3+4)").unwrap(),
+            vec![
+                (Token::Number(12), 0),
+                (Token::Operator(Operator::Multiply), 3),
+                (Token::OpenParen, 5),
+                (Token::Number(3), 6),
+                (Token::Operator(Operator::Add), 7),
+                (Token::Number(4), 8),
+                (Token::CloseParen, 9),
+            ]
+        );
+
+        assert!(matches!(
+            tokenize("1 - 2"),
+            Err(ExpressionParseError::UnexpectedChar {
+                chr: '-',
+                offset: 2
+            })
+        ));
+    }
+}
@@ -0,0 +1,87 @@
+use std::{error::Error, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{ParseError, SolveError, Solved, TypedSolver};
+
+pub mod expression;
+
+use expression::{parse, Expr, Precedence};
+
+#[derive(Debug)]
+pub struct Solution {
+    /// Homework parsed with operators of equal precedence
+    equal: Vec<Expr>,
+    /// Homework parsed with addition evaluated first
+    addition_first: Vec<Expr>,
+}
+
+impl FromStr for Solution {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input_data: &str) -> Result<Self, Self::Err> {
+        let mut equal = Vec::new();
+        let mut addition_first = Vec::new();
+
+        for (idx, line) in input_data.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let parse_line = |precedence| {
+                parse(line, precedence).map_err(|e| ParseError::located(idx + 1, line, e))
+            };
+
+            equal.push(parse_line(Precedence::EQUAL)?);
+            addition_first.push(parse_line(Precedence::ADDITION_FIRST)?);
+        }
+
+        Ok(Self {
+            equal,
+            addition_first,
+        })
+    }
+}
+
+impl Solver for Solution {
+    fn day_number() -> u32 {
+        18
+    }
+
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        let homework = match part {
+            Part::One => &self.equal,
+            Part::Two => &self.addition_first,
+        };
+
+        let sum = homework
+            .iter()
+            .try_fold(0u64, |sum, expr| sum.checked_add(expr.eval()?))
+            .ok_or_else(|| SolveError::no_solution("homework answers overflow"))?;
+
+        Ok(Solved::new(
+            sum,
+            format!("sum of homework answers is {}", sum),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "1 + 2\n2 * (3 + )".parse::<Solution>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 10: Unexpected token `)` (in \"2 * (3 + )\")"
+        );
+    }
+}
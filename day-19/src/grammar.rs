@@ -0,0 +1,415 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use common::Located;
+use displaydoc::Display;
+use thiserror::Error;
+
+pub type RuleId = usize;
+
+/// Example: `1: 2 3 | 3 2` or `4: "a"`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum Rule {
+    Char(char),
+    Alternatives(Vec<Vec<RuleId>>),
+}
+
+/// Rule definition, as it's written in the input
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Definition {
+    id: RuleId,
+    rule: Rule,
+}
+
+#[derive(Debug, Display, Error)]
+pub enum RuleParseError {
+    /// Unknown rule format
+    UnknownFormat,
+    /// Invalid rule id {id:?}: {source}
+    InvalidId { id: String, source: ParseIntError },
+    /// Invalid rule reference {reference:?}: {source}
+    InvalidReference {
+        reference: String,
+        source: ParseIntError,
+        offset: usize,
+    },
+    /// Alternative is empty
+    EmptyAlternative { offset: usize },
+}
+
+impl Located for RuleParseError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::UnknownFormat => None,
+            Self::InvalidId { .. } => Some(0),
+            Self::InvalidReference { offset, .. } | Self::EmptyAlternative { offset } => {
+                Some(offset)
+            }
+        }
+    }
+}
+
+impl FromStr for Definition {
+    type Err = RuleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, body) = s.split_once(": ").ok_or(RuleParseError::UnknownFormat)?;
+        let id = id.parse().map_err(|source| RuleParseError::InvalidId {
+            id: id.to_owned(),
+            source,
+        })?;
+
+        let body_offset = s[..s.len() - body.len()].chars().count();
+
+        let mut chars = body.chars();
+        if let (Some('"'), Some(chr), Some('"'), None) =
+            (chars.next(), chars.next(), chars.next(), chars.next())
+        {
+            return Ok(Self {
+                id,
+                rule: Rule::Char(chr),
+            });
+        }
+
+        let mut offset = body_offset;
+        let alternatives =
+            body.split(" | ")
+                .map(|alternative| {
+                    let start = offset;
+                    offset += alternative.chars().count() + " | ".len();
+
+                    if alternative.is_empty() {
+                        return Err(RuleParseError::EmptyAlternative { offset: start });
+                    }
+
+                    let mut offset = start;
+                    alternative
+                        .split(' ')
+                        .map(|reference| {
+                            let parsed = reference.parse().map_err(|source| {
+                                RuleParseError::InvalidReference {
+                                    reference: reference.to_owned(),
+                                    source,
+                                    offset,
+                                }
+                            });
+                            offset += reference.chars().count() + 1;
+
+                            parsed
+                        })
+                        .collect()
+                })
+                .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            id,
+            rule: Rule::Alternatives(alternatives),
+        })
+    }
+}
+
+impl Definition {
+    pub fn id(&self) -> RuleId {
+        self.id
+    }
+}
+
+impl Rule {
+    fn references(&self) -> impl Iterator<Item = RuleId> + '_ {
+        let alternatives = match self {
+            Self::Char(_) => &[][..],
+            Self::Alternatives(alternatives) => alternatives,
+        };
+
+        alternatives.iter().flatten().copied()
+    }
+}
+
+#[derive(Debug, Display, Error, PartialEq, Eq)]
+pub enum GrammarError {
+    /// rule {rule} refers to undefined rule {reference}
+    UndefinedRule { rule: RuleId, reference: RuleId },
+}
+
+/// Reason for a message to be rejected by the grammar
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum MatchError {
+    /// Rules on the `path` from the root rule failed at `position`
+    RuleFailed { path: Vec<RuleId>, position: usize },
+    /// Root rule matched only up to `position`
+    TrailingInput { position: usize },
+}
+
+impl fmt::Display for MatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RuleFailed { path, position } => {
+                let path = path.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+                write!(
+                    f,
+                    "rule {} failed at position {} (via {})",
+                    path.last().map_or("?", String::as_str),
+                    position,
+                    path.join(" -> ")
+                )
+            }
+            Self::TrailingInput { position } => {
+                write!(f, "unexpected input after position {}", position)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Grammar {
+    rules: BTreeMap<RuleId, Rule>,
+}
+
+/// Partial match of a rule which is being matched at some position
+#[derive(Default)]
+struct Seed {
+    ends: BTreeSet<usize>,
+    /// Whether the rule was reached again at the same position, i.e. it's left-recursive
+    reentered: bool,
+}
+
+/// State of matching a single message
+struct Matcher<'a> {
+    rules: &'a BTreeMap<RuleId, Rule>,
+    message: &'a [char],
+    /// Rules currently being matched at given position
+    in_progress: HashMap<(RuleId, usize), Seed>,
+    path: Vec<RuleId>,
+    /// Path to the terminal rule which failed the furthest into the message
+    furthest_failure: Option<(usize, Vec<RuleId>)>,
+}
+
+impl Matcher<'_> {
+    /// All positions where a match of `rule` starting at `start` may end
+    ///
+    /// Left recursion is resolved by growing the seed: re-entering a rule at the same
+    /// position yields the ends found so far, and the rule is re-matched until they settle
+    fn match_rule(&mut self, rule: RuleId, start: usize) -> BTreeSet<usize> {
+        let key = (rule, start);
+        if let Some(seed) = self.in_progress.get_mut(&key) {
+            seed.reentered = true;
+
+            return seed.ends.clone();
+        }
+
+        self.in_progress.insert(key, Seed::default());
+        self.path.push(rule);
+
+        let ends = loop {
+            let ends = self.match_alternatives(rule, start);
+
+            let seed = self.in_progress.get_mut(&key).expect("seed is in progress");
+            if !seed.reentered || ends == seed.ends {
+                break ends;
+            }
+            seed.ends = ends;
+        };
+
+        self.path.pop();
+        self.in_progress.remove(&key);
+
+        ends
+    }
+
+    fn match_alternatives(&mut self, rule: RuleId, start: usize) -> BTreeSet<usize> {
+        let rules = self.rules;
+        match &rules[&rule] {
+            &Rule::Char(chr) => {
+                if self.message.get(start) == Some(&chr) {
+                    BTreeSet::from([start + 1])
+                } else {
+                    self.record_failure(start);
+
+                    BTreeSet::new()
+                }
+            }
+            Rule::Alternatives(alternatives) => alternatives
+                .iter()
+                .flat_map(|sequence| self.match_sequence(sequence, start))
+                .collect(),
+        }
+    }
+
+    fn match_sequence(&mut self, sequence: &[RuleId], start: usize) -> BTreeSet<usize> {
+        sequence
+            .iter()
+            .fold(BTreeSet::from([start]), |positions, &rule| {
+                positions
+                    .into_iter()
+                    .flat_map(|position| self.match_rule(rule, position))
+                    .collect()
+            })
+    }
+
+    fn record_failure(&mut self, position: usize) {
+        match &self.furthest_failure {
+            Some((furthest, _)) if *furthest >= position => {}
+            _ => self.furthest_failure = Some((position, self.path.clone())),
+        }
+    }
+}
+
+impl Grammar {
+    /// Adds the rule, replacing any previous definition with the same id
+    pub fn define(&mut self, definition: Definition) {
+        self.rules.insert(definition.id, definition.rule);
+    }
+
+    /// Checks that every referenced rule is defined
+    pub fn validate(&self) -> Result<(), GrammarError> {
+        for (&rule, definition) in &self.rules {
+            if let Some(reference) = definition
+                .references()
+                .find(|reference| !self.rules.contains_key(reference))
+            {
+                return Err(GrammarError::UndefinedRule { rule, reference });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn contains(&self, id: RuleId) -> bool {
+        self.rules.contains_key(&id)
+    }
+
+    /// Checks whether the whole `message` matches `root` rule, which must be defined
+    pub fn check(&self, root: RuleId, message: &str) -> Result<(), MatchError> {
+        let message = message.chars().collect::<Vec<_>>();
+
+        let mut matcher = Matcher {
+            rules: &self.rules,
+            message: &message,
+            in_progress: HashMap::new(),
+            path: Vec::new(),
+            furthest_failure: None,
+        };
+
+        let ends = matcher.match_rule(root, 0);
+
+        if ends.contains(&message.len()) {
+            return Ok(());
+        }
+
+        let longest_match = ends.iter().next_back().copied();
+        match (matcher.furthest_failure, longest_match) {
+            (Some((position, path)), end) if end.is_none_or(|end| position >= end) => {
+                Err(MatchError::RuleFailed { path, position })
+            }
+            (_, end) => Err(MatchError::TrailingInput {
+                position: end.unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grammar(rules: &[&str]) -> Grammar {
+        let mut grammar = Grammar::default();
+        for rule in rules {
+            grammar.define(rule.parse().unwrap());
+        }
+        grammar.validate().unwrap();
+
+        grammar
+    }
+
+    #[test]
+    fn test_rule_parse() {
+        let definition: Definition = "1: 2 3 | 3 2".parse().unwrap();
+
+        assert_eq!(definition.id(), 1);
+        assert_eq!(
+            definition.rule,
+            Rule::Alternatives(vec![vec![2, 3], vec![3, 2]])
+        );
+
+        let definition: Definition = "4: \"a\"".parse().unwrap();
+
+        assert_eq!(definition.rule, Rule::Char('a'));
+
+        let e = "1: 2 3 | 3 x".parse::<Definition>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "Invalid rule reference \"x\": invalid digit found in string"
+        );
+        assert_eq!(e.offset(), Some(11));
+
+        let e = "1: 2 | 3 ü".parse::<Definition>().unwrap_err();
+
+        assert_eq!(e.offset(), Some(9));
+    }
+
+    #[test]
+    fn test_check() {
+        let grammar = grammar(&[
+            "0: 4 1 5",
+            "1: 2 3 | 3 2",
+            "2: 4 4 | 5 5",
+            "3: 4 5 | 5 4",
+            "4: \"a\"",
+            "5: \"b\"",
+        ]);
+
+        assert_eq!(grammar.check(0, "ababbb"), Ok(()));
+        assert_eq!(grammar.check(0, "abbbab"), Ok(()));
+
+        assert_eq!(
+            grammar.check(0, "bababa"),
+            Err(MatchError::RuleFailed {
+                path: vec![0, 4],
+                position: 0
+            })
+        );
+        assert_eq!(
+            grammar.check(0, "aaaabbb"),
+            Err(MatchError::TrailingInput { position: 6 })
+        );
+        assert_eq!(
+            grammar.check(0, "aaaabbb").unwrap_err().to_string(),
+            "unexpected input after position 6"
+        );
+        assert_eq!(
+            grammar.check(0, "abbbba").unwrap_err().to_string(),
+            "rule 4 failed at position 4 (via 0 -> 1 -> 3 -> 4)"
+        );
+    }
+
+    #[test]
+    fn test_recursive_rules() {
+        let grammar = grammar(&["0: 1 | 1 0", "1: \"a\"", "2: 2 1 | 1"]);
+
+        assert_eq!(grammar.check(0, "aaaa"), Ok(()));
+        // left recursion doesn't loop forever
+        assert_eq!(grammar.check(2, "aaa"), Ok(()));
+    }
+
+    #[test]
+    fn test_undefined_rule() {
+        let mut grammar = Grammar::default();
+        grammar.define("0: 1 2".parse().unwrap());
+        grammar.define("1: \"a\"".parse().unwrap());
+
+        assert_eq!(
+            grammar.validate(),
+            Err(GrammarError::UndefinedRule {
+                rule: 0,
+                reference: 2
+            })
+        );
+    }
+}
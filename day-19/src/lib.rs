@@ -0,0 +1,233 @@
+use std::{collections::BTreeMap, error::Error, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{ParseError, SolveError, Solved, TypedSolver};
+use displaydoc::Display;
+use thiserror::Error;
+
+pub mod grammar;
+
+use grammar::{Definition, Grammar, RuleId};
+
+const ROOT_RULE: RuleId = 0;
+/// Replacements for part two, which make the grammar recursive
+const LOOPED_RULES: [&str; 2] = ["8: 42 | 42 8", "11: 42 31 | 42 11 31"];
+
+#[derive(Debug)]
+pub struct Solution {
+    grammar: Grammar,
+    messages: Vec<String>,
+}
+
+#[derive(Debug, Display, Error)]
+/// Error while parsing rules and messages
+enum MessagesParseError {
+    /// Rule {0} is not defined
+    NoRootRule(RuleId),
+}
+
+impl FromStr for Solution {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input_data: &str) -> Result<Self, Self::Err> {
+        let eof = input_data.lines().count() + 1;
+        let mut lines = input_data
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| (idx + 1, line));
+
+        let mut grammar = Grammar::default();
+        let mut definitions = BTreeMap::new();
+        for (number, line) in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+
+            let definition = line
+                .parse::<Definition>()
+                .map_err(|e| ParseError::located(number, line, e))?;
+            definitions.insert(definition.id(), (number, line));
+            grammar.define(definition);
+        }
+
+        if let Err(e) = grammar.validate() {
+            let grammar::GrammarError::UndefinedRule { rule, .. } = e;
+            let (number, line) = definitions[&rule];
+
+            return Err(ParseError::new(number, line, e).into());
+        }
+        if !grammar.contains(ROOT_RULE) {
+            return Err(ParseError::new(eof, "", MessagesParseError::NoRootRule(ROOT_RULE)).into());
+        }
+
+        let messages = lines
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(_, line)| line.to_owned())
+            .collect();
+
+        Ok(Self { grammar, messages })
+    }
+}
+
+impl Solution {
+    fn count_matching(&self, grammar: &Grammar) -> usize {
+        self.messages
+            .iter()
+            .filter(|message| grammar.check(ROOT_RULE, message).is_ok())
+            .count()
+    }
+
+    /// Grammar with the looped rules of part two, if the rules they refer to are defined
+    fn looped_grammar(&self) -> Result<Grammar, SolveError> {
+        let mut grammar = self.grammar.clone();
+        for rule in LOOPED_RULES {
+            grammar.define(rule.parse().map_err(SolveError::parse)?);
+        }
+
+        grammar
+            .validate()
+            .map_err(|e| SolveError::no_solution(e.to_string()))?;
+
+        Ok(grammar)
+    }
+}
+
+impl Solver for Solution {
+    fn day_number() -> u32 {
+        19
+    }
+
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        let matching = match part {
+            Part::One => self.count_matching(&self.grammar),
+            Part::Two => self.count_matching(&self.looped_grammar()?),
+        };
+
+        Ok(Solved::new(
+            matching,
+            format!("{} messages match rule {}", matching, ROOT_RULE),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Answer;
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_simple_grammar() {
+        let input = indoc!(
+            r#"
+            0: 4 1 5
+            1: 2 3 | 3 2
+            2: 4 4 | 5 5
+            3: 4 5 | 5 4
+            4: "a"
+            5: "b"
+
+            ababbb
+            bababa
+            abbbab
+            aaabbb
+            aaaabbb
+        "#
+        );
+
+        let solution = input.parse::<Solution>().unwrap();
+
+        assert_eq!(solution.count_matching(&solution.grammar), 2);
+    }
+
+    #[test]
+    fn test_looped_rules() {
+        let input = indoc!(
+            r#"
+            42: 9 14 | 10 1
+            9: 14 27 | 1 26
+            10: 23 14 | 28 1
+            1: "a"
+            11: 42 31
+            5: 1 14 | 15 1
+            19: 14 1 | 14 14
+            12: 24 14 | 19 1
+            16: 15 1 | 14 14
+            31: 14 17 | 1 13
+            6: 14 14 | 1 14
+            2: 1 24 | 14 4
+            0: 8 11
+            13: 14 3 | 1 12
+            15: 1 | 14
+            17: 14 2 | 1 7
+            23: 25 1 | 22 14
+            28: 16 1
+            4: 1 1
+            20: 14 14 | 1 15
+            3: 5 14 | 16 1
+            27: 1 6 | 14 18
+            14: "b"
+            21: 14 1 | 1 14
+            25: 1 1 | 1 14
+            22: 14 14
+            8: 42
+            26: 14 22 | 1 20
+            18: 15 15
+            7: 14 5 | 1 21
+            24: 14 1
+
+            abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+            bbabbbbaabaabba
+            babbbbaabbbbbabbbbbbaabaaabaaa
+            aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+            bbbbbbbaaaabbbbaaabbabaaa
+            bbbababbbbaaaaaaaabbababaaababaabab
+            ababaaaaaabaaab
+            ababaaaaabbbaba
+            baabbaaaabbaaaababbaababb
+            abbbbabbbbaaaababbbbbbaaaababb
+            aaaaabbaabaaaaababaa
+            aaaabbaaaabbaaa
+            aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+            babaaabbbaaabaababbaabababaaab
+            aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba
+        "#
+        );
+
+        let solution = input.parse::<Solution>().unwrap();
+
+        assert_eq!(solution.count_matching(&solution.grammar), 3);
+        assert_eq!(
+            solution.count_matching(&solution.looped_grammar().unwrap()),
+            12
+        );
+        assert_eq!(
+            solution.solve_typed(Part::Two).unwrap().answer,
+            Answer::Integer(12)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "0: 1 2\n1: \"a\"\n\nab".parse::<Solution>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 1: rule 0 refers to undefined rule 2 (in \"0: 1 2\")"
+        );
+
+        let e = "0: 1 | 1 x".parse::<Solution>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 10: Invalid rule reference \"x\": invalid digit found in string (in \"0: 1 | 1 x\")"
+        );
+    }
+}
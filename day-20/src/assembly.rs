@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use common::Orientation;
+
+use crate::picture::Picture;
+
+/// Tile index along with the orientation it's placed in
+pub(crate) type Placement = (usize, Orientation);
+
+/// Square arrangement of tiles, row by row
+#[derive(Debug, Clone)]
+pub(crate) struct Arrangement {
+    pub(crate) side: usize,
+    pub(crate) placements: Vec<Placement>,
+}
+
+impl Arrangement {
+    pub(crate) fn corners(&self) -> [Placement; 4] {
+        let last = self.placements.len() - 1;
+
+        [
+            self.placements[0],
+            self.placements[self.side - 1],
+            self.placements[last + 1 - self.side],
+            self.placements[last],
+        ]
+    }
+}
+
+/// Depth-first search of tile placements, filling the square row by row
+struct Assembler {
+    side: usize,
+    /// Every tile in every orientation
+    oriented: Vec<HashMap<Orientation, Picture>>,
+    /// Placements by their left edge
+    by_left: HashMap<Vec<bool>, Vec<Placement>>,
+    /// Placements by their top edge
+    by_top: HashMap<Vec<bool>, Vec<Placement>>,
+    used: Vec<bool>,
+    placements: Vec<Placement>,
+}
+
+impl Assembler {
+    fn picture(&self, (tile, orientation): Placement) -> &Picture {
+        &self.oriented[tile][&orientation]
+    }
+
+    fn candidates(&self) -> Vec<Placement> {
+        let position = self.placements.len();
+        let (x, y) = (position % self.side, position / self.side);
+
+        let above = (y > 0).then(|| self.picture(self.placements[position - self.side]));
+
+        if x > 0 {
+            let right_edge = self.picture(self.placements[position - 1]).right();
+
+            self.by_left
+                .get(&right_edge)
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(|&placement| {
+                    above.is_none_or(|above| above.bottom() == self.picture(placement).top())
+                })
+                .collect()
+        } else if let Some(above) = above {
+            self.by_top
+                .get(above.bottom())
+                .into_iter()
+                .flatten()
+                .copied()
+                .collect()
+        } else {
+            (0..self.oriented.len())
+                .flat_map(|tile| {
+                    Orientation::ALL
+                        .iter()
+                        .map(move |&orientation| (tile, orientation))
+                })
+                .collect()
+        }
+    }
+
+    fn search(&mut self) -> bool {
+        if self.placements.len() == self.oriented.len() {
+            return true;
+        }
+
+        for placement in self.candidates() {
+            let (tile, _) = placement;
+            if self.used[tile] {
+                continue;
+            }
+
+            self.used[tile] = true;
+            self.placements.push(placement);
+
+            if self.search() {
+                return true;
+            }
+
+            self.placements.pop();
+            self.used[tile] = false;
+        }
+
+        false
+    }
+}
+
+/// Arranges square tiles of equal size into a square so that adjacent edges match
+pub(crate) fn assemble(tiles: &[Picture]) -> Option<Arrangement> {
+    let side = (0..=tiles.len()).find(|side| side * side >= tiles.len())?;
+    if side * side != tiles.len() || side == 0 {
+        return None;
+    }
+
+    let oriented = tiles
+        .iter()
+        .map(|tile| {
+            Orientation::ALL
+                .iter()
+                .map(|&orientation| (orientation, tile.oriented(orientation)))
+                .collect::<HashMap<_, _>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut by_left = HashMap::<_, Vec<_>>::new();
+    let mut by_top = HashMap::<_, Vec<_>>::new();
+    for (tile, pictures) in oriented.iter().enumerate() {
+        for (&orientation, picture) in pictures {
+            by_left
+                .entry(picture.left())
+                .or_default()
+                .push((tile, orientation));
+            by_top
+                .entry(picture.top().to_vec())
+                .or_default()
+                .push((tile, orientation));
+        }
+    }
+
+    let mut assembler = Assembler {
+        side,
+        oriented,
+        by_left,
+        by_top,
+        used: vec![false; tiles.len()],
+        placements: Vec::with_capacity(tiles.len()),
+    };
+
+    assembler.search().then_some(Arrangement {
+        side,
+        placements: assembler.placements,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picture(rows: &[&str]) -> Picture {
+        Picture::from_rows(rows.iter().copied()).unwrap()
+    }
+
+    #[test]
+    fn test_assemble() {
+        // #.#|#..
+        // ..#|#.#
+        // ##.|.##
+        // ---+---
+        // ##.|.##
+        // #..|..#
+        // .#.|.##
+        let tiles = [
+            picture(&["#.#", "..#", "##."]),
+            picture(&["#..", "#.#", ".##"]),
+            picture(&["##.", "#..", ".#."]),
+            picture(&[".##", "..#", ".##"]),
+        ]
+        .iter()
+        .map(|tile| tile.oriented(Orientation::new(true, 1)))
+        .collect::<Vec<_>>();
+
+        let arrangement = assemble(&tiles).unwrap();
+
+        assert_eq!(arrangement.side, 2);
+
+        let [top_left, top_right, bottom_left, bottom_right] = arrangement
+            .corners()
+            .map(|(tile, orientation)| tiles[tile].oriented(orientation));
+
+        assert_eq!(top_left.right(), top_right.left());
+        assert_eq!(bottom_left.right(), bottom_right.left());
+        assert_eq!(top_left.bottom(), bottom_left.top());
+        assert_eq!(top_right.bottom(), bottom_right.top());
+    }
+
+    #[test]
+    fn test_assemble_impossible() {
+        let empty = picture(&["...", "...", "..."]);
+        let full = picture(&["###", "###", "###"]);
+
+        assert!(assemble(&[empty.clone(), empty.clone(), empty.clone(), full]).is_none());
+        assert!(assemble(&[empty.clone(), empty.clone()]).is_none());
+        assert!(assemble(&[empty]).is_some());
+        assert!(assemble(&[]).is_none());
+    }
+}
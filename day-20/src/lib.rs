@@ -0,0 +1,503 @@
+use std::{collections::HashSet, error::Error, fmt, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{Orientation, ParseError, SolveError, Solved, TypedSolver};
+use displaydoc::Display;
+use thiserror::Error;
+
+mod assembly;
+mod picture;
+mod tile;
+
+use assembly::{assemble, Arrangement};
+use picture::Picture;
+use tile::Tile;
+
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+#[derive(Debug)]
+pub struct Solution {
+    tiles: Vec<Tile>,
+}
+
+#[derive(Debug, Display, Error)]
+/// Error while parsing tiles
+enum TilesParseError {
+    /// Tile is {got} pixels wide, but previous ones are {expected}
+    SizeMismatch { expected: usize, got: usize },
+}
+
+impl FromStr for Solution {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input_data: &str) -> Result<Self, Self::Err> {
+        let eof = input_data.lines().count() + 1;
+        let lines = input_data
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| (idx + 1, line))
+            .collect::<Vec<_>>();
+
+        let mut tiles: Vec<Tile> = Vec::new();
+        for block in lines.split(|(_, line)| line.is_empty()) {
+            if block.is_empty() {
+                continue;
+            }
+
+            let tile = Tile::parse(block, eof)?;
+
+            if let Some(first) = tiles.first() {
+                let (expected, got) = (first.picture.width(), tile.picture.width());
+                if expected != got {
+                    let (number, header) = block[0];
+
+                    return Err(ParseError::new(
+                        number,
+                        header,
+                        TilesParseError::SizeMismatch { expected, got },
+                    )
+                    .into());
+                }
+            }
+
+            tiles.push(tile);
+        }
+
+        Ok(Self { tiles })
+    }
+}
+
+fn sea_monster() -> Picture {
+    let rows = SEA_MONSTER.map(|row| row.replace(' ', "."));
+
+    Picture::from_rows(rows.iter().map(String::as_str)).expect("sea monster is a valid picture")
+}
+
+impl Solution {
+    fn arrangement(&self) -> Result<Arrangement, SolveError> {
+        let pictures = self
+            .tiles
+            .iter()
+            .map(|tile| tile.picture.clone())
+            .collect::<Vec<_>>();
+
+        assemble(&pictures).ok_or_else(|| SolveError::no_solution("tiles can't be assembled"))
+    }
+
+    /// Assembled image with tile borders removed, in an arbitrary orientation
+    fn image(&self, arrangement: &Arrangement) -> Picture {
+        let rows = arrangement
+            .placements
+            .chunks(arrangement.side)
+            .map(|row| {
+                row.iter()
+                    .map(|&(tile, orientation)| {
+                        self.tiles[tile]
+                            .picture
+                            .oriented(orientation)
+                            .without_border()
+                    })
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+
+        Picture::join(&rows)
+    }
+
+    /// Assembled image with tile borders removed, renderable with `#` for set pixels and
+    /// `.` for the others
+    pub fn assembled_image(&self) -> Result<impl fmt::Display, SolveError> {
+        Ok(self.image(&self.arrangement()?))
+    }
+
+    /// Number of set pixels which aren't part of any sea monster, for the orientation
+    /// where monsters are found
+    fn water_roughness(image: &Picture) -> Option<usize> {
+        let monster = sea_monster();
+
+        Orientation::ALL.iter().find_map(|&orientation| {
+            let image = image.oriented(orientation);
+
+            let monster_pixels = image
+                .find(&monster)
+                .flat_map(|(x, y)| {
+                    let monster = &monster;
+
+                    (0..monster.height())
+                        .flat_map(move |dy| (0..monster.width()).map(move |dx| (dx, dy)))
+                        .filter(|&(dx, dy)| monster.get(dx, dy))
+                        .map(move |(dx, dy)| (x + dx, y + dy))
+                })
+                .collect::<HashSet<_>>();
+
+            (!monster_pixels.is_empty()).then(|| image.count_set() - monster_pixels.len())
+        })
+    }
+}
+
+impl Solver for Solution {
+    fn day_number() -> u32 {
+        20
+    }
+
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        let arrangement = self.arrangement()?;
+
+        match part {
+            Part::One => {
+                let product = arrangement
+                    .corners()
+                    .iter()
+                    .map(|&(tile, _)| self.tiles[tile].id)
+                    .try_fold(1u64, u64::checked_mul)
+                    .ok_or_else(|| SolveError::no_solution("product of corner ids overflows"))?;
+
+                Ok(Solved::new(
+                    product,
+                    format!("product of corner tile ids is {}", product),
+                ))
+            }
+            Part::Two => {
+                let roughness = Self::water_roughness(&self.image(&arrangement))
+                    .ok_or_else(|| SolveError::no_solution("no sea monsters found"))?;
+
+                Ok(Solved::new(
+                    roughness,
+                    format!("water roughness is {}", roughness),
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Answer;
+    use indoc::indoc;
+
+    use super::*;
+
+    const EXAMPLE: &str = indoc!(
+        "Tile 2311:
+        ..##.#..#.
+        ##..#.....
+        #...##..#.
+        ####.#...#
+        ##.##.###.
+        ##...#.###
+        .#.#.#..##
+        ..#....#..
+        ###...#.#.
+        ..###..###
+
+        Tile 1951:
+        #.##...##.
+        #.####...#
+        .....#..##
+        #...######
+        .##.#....#
+        .###.#####
+        ###.##.##.
+        .###....#.
+        ..#.#..#.#
+        #...##.#..
+
+        Tile 1171:
+        ####...##.
+        #..##.#..#
+        ##.#..#.#.
+        .###.####.
+        ..###.####
+        .##....##.
+        .#...####.
+        #.##.####.
+        ####..#...
+        .....##...
+
+        Tile 1427:
+        ###.##.#..
+        .#..#.##..
+        .#.##.#..#
+        #.#.#.##.#
+        ....#...##
+        ...##..##.
+        ...#.#####
+        .#.####.#.
+        ..#..###.#
+        ..##.#..#.
+
+        Tile 1489:
+        ##.#.#....
+        ..##...#..
+        .##..##...
+        ..#...#...
+        #####...#.
+        #..#.#.#.#
+        ...#.#.#..
+        ##.#...##.
+        ..##.##.##
+        ###.##.#..
+
+        Tile 2473:
+        #....####.
+        #..#.##...
+        #.##..#...
+        ######.#.#
+        .#...#.#.#
+        .#########
+        .###.#..#.
+        ########.#
+        ##...##.#.
+        ..###.#.#.
+
+        Tile 2971:
+        ..#.#....#
+        #...###...
+        #.#.###...
+        ##.##..#..
+        .#####..##
+        .#..####.#
+        #..#.#..#.
+        ..####.###
+        ..#.#.###.
+        ...#.#.#.#
+
+        Tile 2729:
+        ...#.#.#.#
+        ####.#....
+        ..#.#.....
+        ....#..#.#
+        .##..##.#.
+        .#.####...
+        ####.#.#..
+        ##.####...
+        ##..#.##..
+        #.##...##.
+
+        Tile 3079:
+        #.#.#####.
+        .#..######
+        ..#.......
+        ######....
+        ####.#..#.
+        .#...#.##.
+        #.#####.##
+        ..#.###...
+        ..#.......
+        ..#.###..."
+    );
+
+    const TILE_SIZE: usize = 10;
+    const SIDE: usize = 3;
+
+    /// Deterministic xorshift generator, so puzzles are the same on every run
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+
+            self.0
+        }
+    }
+
+    /// Cuts `image` into tiles, surrounding their inner parts with random borders,
+    /// then shuffles and rotates them. Returns the puzzle input and ids of corner tiles
+    fn make_puzzle(image: &Picture, random: &mut Random) -> (String, Vec<u64>) {
+        let inner = TILE_SIZE - 2;
+        let side = image.width() / inner;
+        // tiles share borders, so they overlap by one pixel in the full picture
+        let full_side = side * (TILE_SIZE - 1) + 1;
+
+        let full = (0..full_side)
+            .flat_map(|y| (0..full_side).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let on_border = |c: usize| c.is_multiple_of(TILE_SIZE - 1);
+                let inner_coord = |c: usize| c - c / (TILE_SIZE - 1) - 1;
+
+                if on_border(x) || on_border(y) {
+                    random.next().is_multiple_of(2)
+                } else {
+                    image.get(inner_coord(x), inner_coord(y))
+                }
+            })
+            .collect();
+        let full = Picture::new(full, full_side);
+
+        let mut tiles = (0..side * side)
+            .map(|position| {
+                let (left, top) = (
+                    position % side * (TILE_SIZE - 1),
+                    position / side * (TILE_SIZE - 1),
+                );
+                let pixels = (0..TILE_SIZE)
+                    .flat_map(|y| (0..TILE_SIZE).map(move |x| (x, y)))
+                    .map(|(x, y)| full.get(left + x, top + y))
+                    .collect();
+                let orientation = Orientation::ALL[random.next() as usize % 8];
+
+                (
+                    1000 + position as u64,
+                    Picture::new(pixels, TILE_SIZE).oriented(orientation),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let corners = [0, side - 1, side * (side - 1), side * side - 1]
+            .iter()
+            .map(|&position| tiles[position].0)
+            .collect();
+
+        for idx in (1..tiles.len()).rev() {
+            tiles.swap(idx, random.next() as usize % (idx + 1));
+        }
+
+        let input = tiles
+            .iter()
+            .map(|(id, picture)| format!("Tile {}:\n{}\n", id, picture))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        (input, corners)
+    }
+
+    fn random_image(random: &mut Random) -> Picture {
+        let side = SIDE * (TILE_SIZE - 2);
+        // sparse enough for sea monsters not to appear by chance
+        let pixels = (0..side * side)
+            .map(|_| random.next().is_multiple_of(4))
+            .collect();
+
+        Picture::new(pixels, side)
+    }
+
+    #[test]
+    fn test_example() {
+        let solution = EXAMPLE.parse::<Solution>().unwrap();
+
+        assert_eq!(
+            solution.solve_typed(Part::One).unwrap().answer,
+            Answer::from(20899048083289u64)
+        );
+        assert_eq!(
+            solution.solve_typed(Part::Two).unwrap().answer,
+            Answer::from(273usize)
+        );
+    }
+
+    /// Assembled example image as the puzzle shows it
+    const EXAMPLE_IMAGE: &str = indoc!(
+        ".#.#..#.##...#.##..#####
+        ###....#.#....#..#......
+        ##.##.###.#.#..######...
+        ###.#####...#.#####.#..#
+        ##.#....#.##.####...#.##
+        ...########.#....#####.#
+        ....#..#...##..#.#.###..
+        .####...#..#.....#......
+        #..#.##..#..###.#.##....
+        #.####..#.####.#.#.###..
+        ###.#.#...#.######.#..##
+        #.####....##..########.#
+        ##..##.#...#...#.#.#.#..
+        ...#..#..#.#.##..###.###
+        .#.#....#.##.#...###.##.
+        ###.#...#..#.##.######..
+        .#.#.###.##.##.#..#.##..
+        .####.###.#...###.#..#.#
+        ..#.#..#..#.#.#.####.###
+        #..####...#.#.#.###.###.
+        #####..#####...###....##
+        #.##..#..#...#..####...#
+        .#.###..##..##..####.##.
+        ...###...##...#...#..###"
+    );
+
+    #[test]
+    fn test_example_image() {
+        let solution = EXAMPLE.parse::<Solution>().unwrap();
+        let image = solution.assembled_image().unwrap().to_string();
+
+        assert_eq!(image.lines().count(), 24);
+
+        let image = Picture::from_rows(image.lines()).unwrap();
+        let expected = Picture::from_rows(EXAMPLE_IMAGE.lines()).unwrap();
+
+        assert!(Orientation::ALL
+            .iter()
+            .any(|&orientation| image.oriented(orientation) == expected));
+    }
+
+    #[test]
+    fn test_assembly() {
+        let mut random = Random(0x2020_1220);
+        let image = random_image(&mut random);
+        let (input, corners) = make_puzzle(&image, &mut random);
+
+        let solution = input.parse::<Solution>().unwrap();
+
+        assert_eq!(
+            solution.solve_typed(Part::One).unwrap().answer,
+            Answer::from(corners.iter().product::<u64>())
+        );
+
+        let assembled = solution.image(&solution.arrangement().unwrap());
+
+        assert!(Orientation::ALL
+            .iter()
+            .any(|&orientation| assembled.oriented(orientation) == image));
+    }
+
+    #[test]
+    fn test_water_roughness() {
+        let mut random = Random(0x5ea_0000);
+        let image = random_image(&mut random);
+        let monster = sea_monster();
+
+        let mut pixels = (0..image.height())
+            .flat_map(|y| (0..image.width()).map(move |x| (x, y)))
+            .map(|(x, y)| image.get(x, y))
+            .collect::<Vec<_>>();
+        for (x, y) in [(1, 2), (3, 10), (2, 13)] {
+            for dy in 0..monster.height() {
+                for dx in 0..monster.width() {
+                    pixels[x + dx + (y + dy) * image.width()] |= monster.get(dx, dy);
+                }
+            }
+        }
+        let image = Picture::new(pixels, image.width());
+
+        let monster_pixels = monster.count_set() * 3;
+        let (input, _) = make_puzzle(&image, &mut random);
+        let solution = input.parse::<Solution>().unwrap();
+
+        assert_eq!(
+            solution.solve_typed(Part::Two).unwrap().answer,
+            Answer::from(image.count_set() - monster_pixels)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "Tile 1:\n#..\n...\n...\n\nTile 2:\n#...\n....\n....\n....\n"
+            .parse::<Solution>()
+            .unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 6: Tile is 4 pixels wide, but previous ones are 3 (in \"Tile 2:\")"
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt;
+
+use common::{Located, Orientation};
+use displaydoc::Display;
+use thiserror::Error;
+
+/// Rectangular black and white picture, `#` pixels are set
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Picture {
+    pixels: Vec<bool>,
+    width: usize,
+}
+
+#[derive(Debug, Display, Error)]
+pub(crate) enum PictureParseError {
+    /// Invalid pixel {chr:?}
+    InvalidPixel { chr: char, offset: usize },
+    /// Expected row of {expected} pixels, got {got}
+    InconsistentWidth { expected: usize, got: usize },
+    /// Empty picture
+    EmptyPicture,
+}
+
+impl Located for PictureParseError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::InvalidPixel { offset, .. } => Some(offset),
+            Self::InconsistentWidth { .. } | Self::EmptyPicture => None,
+        }
+    }
+}
+
+impl Picture {
+    pub(crate) fn new(pixels: Vec<bool>, width: usize) -> Self {
+        debug_assert!(width > 0 && pixels.len().is_multiple_of(width));
+
+        Self { pixels, width }
+    }
+
+    /// Parses rows of the picture one by one, errors are reported with the row index
+    pub(crate) fn from_rows<'a>(
+        rows: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, (usize, PictureParseError)> {
+        let mut pixels = Vec::new();
+        let mut width = None;
+
+        for (idx, row) in rows.into_iter().enumerate() {
+            let expected = *width.get_or_insert(row.chars().count());
+            let got = row.chars().count();
+            if got != expected {
+                return Err((idx, PictureParseError::InconsistentWidth { expected, got }));
+            }
+
+            for (offset, chr) in row.chars().enumerate() {
+                pixels.push(match chr {
+                    '#' => true,
+                    '.' => false,
+                    chr => return Err((idx, PictureParseError::InvalidPixel { chr, offset })),
+                });
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self::new(pixels, width)),
+            _ => Err((0, PictureParseError::EmptyPicture)),
+        }
+    }
+
+    pub(crate) fn width(&self) -> usize {
+        self.width
+    }
+
+    pub(crate) fn height(&self) -> usize {
+        self.pixels.len() / self.width
+    }
+
+    pub(crate) fn get(&self, x: usize, y: usize) -> bool {
+        self.pixels[x + y * self.width]
+    }
+
+    pub(crate) fn oriented(&self, orientation: Orientation) -> Self {
+        let (pixels, width) = orientation.apply(&self.pixels, self.width);
+
+        Self::new(pixels, width)
+    }
+
+    pub(crate) fn top(&self) -> &[bool] {
+        &self.pixels[..self.width]
+    }
+
+    pub(crate) fn bottom(&self) -> &[bool] {
+        &self.pixels[self.pixels.len() - self.width..]
+    }
+
+    pub(crate) fn left(&self) -> Vec<bool> {
+        self.pixels.iter().step_by(self.width).copied().collect()
+    }
+
+    pub(crate) fn right(&self) -> Vec<bool> {
+        self.pixels[self.width - 1..]
+            .iter()
+            .step_by(self.width)
+            .copied()
+            .collect()
+    }
+
+    /// Picture without its outermost rows and columns
+    pub(crate) fn without_border(&self) -> Self {
+        let pixels = self
+            .pixels
+            .chunks_exact(self.width)
+            .skip(1)
+            .take(self.height().saturating_sub(2))
+            .flat_map(|row| &row[1..self.width - 1])
+            .copied()
+            .collect();
+
+        Self::new(pixels, self.width - 2)
+    }
+
+    /// Joins rows of equally sized pictures into a single one
+    pub(crate) fn join(rows: &[Vec<Self>]) -> Self {
+        let mut pixels = Vec::new();
+        let mut width = 0;
+
+        for row in rows {
+            width = row.iter().map(Self::width).sum();
+            let height = row.first().map_or(0, Self::height);
+
+            for y in 0..height {
+                for picture in row {
+                    pixels.extend((0..picture.width).map(|x| picture.get(x, y)));
+                }
+            }
+        }
+
+        Self::new(pixels, width)
+    }
+
+    pub(crate) fn count_set(&self) -> usize {
+        self.pixels.iter().filter(|&&pixel| pixel).count()
+    }
+
+    /// Top-left corners of every placement where all set pixels of `pattern` are set
+    pub(crate) fn find<'a>(
+        &'a self,
+        pattern: &'a Self,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let columns = (self.width + 1).saturating_sub(pattern.width);
+        let rows = (self.height() + 1).saturating_sub(pattern.height());
+
+        (0..rows)
+            .flat_map(move |y| (0..columns).map(move |x| (x, y)))
+            .filter(move |&(x, y)| {
+                (0..pattern.height()).all(|dy| {
+                    (0..pattern.width).all(|dx| !pattern.get(dx, dy) || self.get(x + dx, y + dy))
+                })
+            })
+    }
+}
+
+impl fmt::Display for Picture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self
+            .pixels
+            .chunks_exact(self.width)
+            .map(|row| {
+                row.iter()
+                    .map(|&pixel| if pixel { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        write!(f, "{}", rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picture(rows: &[&str]) -> Picture {
+        Picture::from_rows(rows.iter().copied()).unwrap()
+    }
+
+    #[test]
+    fn test_edges() {
+        let picture = picture(&["#..", "..#", "##."]);
+
+        assert_eq!(picture.top(), [true, false, false]);
+        assert_eq!(picture.bottom(), [true, true, false]);
+        assert_eq!(picture.left(), [true, false, true]);
+        assert_eq!(picture.right(), [false, true, false]);
+        assert_eq!(picture.without_border().to_string(), ".");
+        assert_eq!(
+            picture.oriented(Orientation::new(false, 1)).to_string(),
+            "#.#\n#..\n.#."
+        );
+    }
+
+    #[test]
+    fn test_join() {
+        let a = picture(&["#.", ".#"]);
+        let b = picture(&["..", "##"]);
+
+        let joined = Picture::join(&[vec![a.clone(), b.clone()], vec![b, a]]);
+
+        assert_eq!(joined.to_string(), "#...\n.###\n..#.\n##.#");
+    }
+
+    #[test]
+    fn test_find() {
+        let pattern = picture(&["#.", "##"]);
+        let picture = picture(&["#..#", "##.#", ".###"]);
+
+        assert_eq!(picture.find(&pattern).collect::<Vec<_>>(), [(0, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let (idx, e) = Picture::from_rows(["#.", "#"]).unwrap_err();
+
+        assert_eq!(idx, 1);
+        assert_eq!(e.to_string(), "Expected row of 2 pixels, got 1");
+
+        let (idx, e) = Picture::from_rows(["#.", "#x"]).unwrap_err();
+
+        assert_eq!(idx, 1);
+        assert!(matches!(
+            e,
+            PictureParseError::InvalidPixel {
+                chr: 'x',
+                offset: 1
+            }
+        ));
+    }
+}
@@ -0,0 +1,118 @@
+use std::num::ParseIntError;
+
+use common::ParseError;
+use displaydoc::Display;
+use thiserror::Error;
+
+use crate::picture::Picture;
+
+const HEADER_PREFIX: &str = "Tile ";
+const HEADER_SUFFIX: &str = ":";
+
+#[derive(Debug, Clone)]
+pub(crate) struct Tile {
+    pub(crate) id: u64,
+    pub(crate) picture: Picture,
+}
+
+#[derive(Debug, Display, Error)]
+pub(crate) enum TileParseError {
+    /// Expected header like "Tile 42:"
+    InvalidHeader,
+    /// Invalid tile id {id:?}: {source}
+    InvalidId { id: String, source: ParseIntError },
+    /// Tile is {width}x{height}, but must be square
+    NotSquare { width: usize, height: usize },
+    /// Tile must be at least 3 pixels wide to have an inner part
+    TooSmall,
+}
+
+impl Tile {
+    /// Parses the header and rows of a tile, given along with their line numbers
+    pub(crate) fn parse(lines: &[(usize, &str)], eof: usize) -> Result<Self, ParseError> {
+        let (&(header_number, header), rows) = lines
+            .split_first()
+            .ok_or_else(|| ParseError::new(eof, "", TileParseError::InvalidHeader))?;
+        let header_error = |e| ParseError::new(header_number, header, e);
+
+        let id = header
+            .strip_prefix(HEADER_PREFIX)
+            .and_then(|header| header.strip_suffix(HEADER_SUFFIX))
+            .ok_or_else(|| header_error(TileParseError::InvalidHeader))?;
+        let id = id.parse().map_err(|source| {
+            header_error(TileParseError::InvalidId {
+                id: id.to_owned(),
+                source,
+            })
+            .at_offset(HEADER_PREFIX.len())
+        })?;
+
+        let picture =
+            Picture::from_rows(rows.iter().map(|&(_, row)| row)).map_err(|(idx, e)| match rows
+                .get(idx)
+            {
+                Some(&(number, row)) => ParseError::located(number, row, e),
+                None => ParseError::new(header_number + 1, "", e),
+            })?;
+
+        let (width, height) = (picture.width(), picture.height());
+        if width != height {
+            return Err(header_error(TileParseError::NotSquare { width, height }));
+        }
+        if width < 3 {
+            return Err(header_error(TileParseError::TooSmall));
+        }
+
+        Ok(Self { id, picture })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(lines: &[&str]) -> Result<Tile, ParseError> {
+        let lines = lines
+            .iter()
+            .enumerate()
+            .map(|(idx, &line)| (idx + 1, line))
+            .collect::<Vec<_>>();
+
+        Tile::parse(&lines, lines.len() + 1)
+    }
+
+    #[test]
+    fn test_parse() {
+        let tile = parse(&["Tile 2311:", "#..", ".#.", "..#"]).unwrap();
+
+        assert_eq!(tile.id, 2311);
+        assert_eq!(tile.picture.to_string(), "#..\n.#.\n..#");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        macro_rules! assert_error_eq {
+            ($lines: expr, $message: expr) => {
+                assert_eq!(parse(&$lines).unwrap_err().to_string(), $message);
+            };
+        }
+
+        assert_error_eq!(
+            ["Tile x:", "#.."],
+            "line 1, column 6: Invalid tile id \"x\": invalid digit found in string (in \"Tile x:\")"
+        );
+        assert_error_eq!(
+            ["Tile 1", "#.."],
+            "line 1: Expected header like \"Tile 42:\" (in \"Tile 1\")"
+        );
+        assert_error_eq!(
+            ["Tile 1:", "#..", "..."],
+            "line 1: Tile is 3x2, but must be square (in \"Tile 1:\")"
+        );
+        assert_error_eq!(
+            ["Tile 1:", "#..", ".o.", "..."],
+            "line 3, column 2: Invalid pixel 'o' (in \".o.\")"
+        );
+        assert_error_eq!(["Tile 1:"], "line 2: Empty picture (in \"\")");
+    }
+}
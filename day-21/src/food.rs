@@ -0,0 +1,131 @@
+use std::{collections::BTreeSet, str::FromStr};
+
+use common::Located;
+use displaydoc::Display;
+use thiserror::Error;
+
+const ALLERGENS_PREFIX: &str = "(contains ";
+const ALLERGENS_SUFFIX: &str = ")";
+const ALLERGENS_SEPARATOR: &str = ", ";
+
+/// Example: `mxmxvkd kfcds sqjhc nhms (contains dairy, fish)`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Food {
+    pub(crate) ingredients: BTreeSet<String>,
+    /// Some of the allergens contained in the food, not necessarily all
+    pub(crate) allergens: BTreeSet<String>,
+}
+
+#[derive(Debug, Display, Error)]
+pub(crate) enum FoodParseError {
+    /// Food has no ingredients
+    NoIngredients,
+    /// Allergens list is not closed
+    UnclosedAllergens { offset: usize },
+    /// Allergen name is empty
+    EmptyAllergen { offset: usize },
+}
+
+impl Located for FoodParseError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::NoIngredients => None,
+            Self::UnclosedAllergens { offset } | Self::EmptyAllergen { offset } => Some(offset),
+        }
+    }
+}
+
+impl FromStr for Food {
+    type Err = FoodParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ingredients, allergens) = match s.find(ALLERGENS_PREFIX) {
+            Some(start) => (&s[..start], Some((s[..start].chars().count(), &s[start..]))),
+            None => (s, None),
+        };
+
+        let ingredients = ingredients
+            .split_whitespace()
+            .map(str::to_owned)
+            .collect::<BTreeSet<_>>();
+        if ingredients.is_empty() {
+            return Err(FoodParseError::NoIngredients);
+        }
+
+        let allergens = match allergens {
+            None => BTreeSet::new(),
+            Some((start, allergens)) => {
+                let list = allergens
+                    .strip_prefix(ALLERGENS_PREFIX)
+                    .and_then(|allergens| allergens.strip_suffix(ALLERGENS_SUFFIX))
+                    .ok_or(FoodParseError::UnclosedAllergens { offset: start })?;
+
+                let mut offset = start + ALLERGENS_PREFIX.chars().count();
+                list.split(ALLERGENS_SEPARATOR)
+                    .map(|allergen| {
+                        if allergen.is_empty() {
+                            return Err(FoodParseError::EmptyAllergen { offset });
+                        }
+                        offset += allergen.chars().count() + ALLERGENS_SEPARATOR.chars().count();
+
+                        Ok(allergen.to_owned())
+                    })
+                    .collect::<Result<_, _>>()?
+            }
+        };
+
+        Ok(Self {
+            ingredients,
+            allergens,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let food = "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)"
+            .parse::<Food>()
+            .unwrap();
+
+        assert_eq!(
+            food.ingredients,
+            BTreeSet::from(["kfcds", "mxmxvkd", "nhms", "sqjhc"].map(str::to_owned))
+        );
+        assert_eq!(
+            food.allergens,
+            BTreeSet::from(["dairy", "fish"].map(str::to_owned))
+        );
+
+        let food = "sqjhc fvjkl".parse::<Food>().unwrap();
+
+        assert!(food.allergens.is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "sqjhc fvjkl (contains soy".parse::<Food>().unwrap_err();
+
+        assert_eq!(e.to_string(), "Allergens list is not closed");
+        assert_eq!(e.offset(), Some(12));
+
+        let e = "sqjhc (contains soy, , fish)".parse::<Food>().unwrap_err();
+
+        assert_eq!(e.to_string(), "Allergen name is empty");
+        assert_eq!(e.offset(), Some(21));
+
+        let e = "crème brûlée (contains égg, , nuts)"
+            .parse::<Food>()
+            .unwrap_err();
+
+        assert_eq!(e.offset(), Some(28));
+
+        assert!(matches!(
+            "(contains soy)".parse::<Food>(),
+            Err(FoodParseError::NoIngredients)
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+    str::FromStr,
+};
+
+use advent_utils::{Part, Solver};
+use common::{assign, parse_lines, AssignmentError, SolveError, Solved, TypedSolver};
+
+mod food;
+
+use food::Food;
+
+#[derive(Debug)]
+pub struct Solution {
+    foods: Vec<Food>,
+}
+
+impl FromStr for Solution {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input_data: &str) -> Result<Self, Self::Err> {
+        let foods = parse_lines(input_data)?;
+
+        Ok(Self { foods })
+    }
+}
+
+impl Solution {
+    /// Ingredients which may contain each allergen: those present in every food listing it
+    fn candidates(&self) -> BTreeMap<&str, BTreeSet<&str>> {
+        let mut candidates = BTreeMap::<_, BTreeSet<_>>::new();
+
+        for food in &self.foods {
+            let ingredients = food.ingredients.iter().map(String::as_str);
+
+            for allergen in &food.allergens {
+                candidates
+                    .entry(allergen.as_str())
+                    .and_modify(|candidates| {
+                        candidates.retain(|ingredient| food.ingredients.contains(*ingredient))
+                    })
+                    .or_insert_with(|| ingredients.clone().collect());
+            }
+        }
+
+        candidates
+    }
+
+    /// Number of times ingredients which can't contain any allergen appear in foods
+    fn safe_appearances(&self) -> usize {
+        let unsafe_ingredients = self
+            .candidates()
+            .into_values()
+            .flatten()
+            .collect::<BTreeSet<_>>();
+
+        self.foods
+            .iter()
+            .flat_map(|food| &food.ingredients)
+            .filter(|ingredient| !unsafe_ingredients.contains(ingredient.as_str()))
+            .count()
+    }
+
+    /// Ingredient containing each allergen
+    fn dangerous_ingredients(&self) -> Result<BTreeMap<&str, &str>, AssignmentError> {
+        assign(self.candidates())
+    }
+}
+
+impl Solver for Solution {
+    fn day_number() -> u32 {
+        21
+    }
+
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        match part {
+            Part::One => {
+                let appearances = self.safe_appearances();
+
+                Ok(Solved::new(
+                    appearances,
+                    format!("safe ingredients appear {} times", appearances),
+                ))
+            }
+            Part::Two => {
+                let dangerous = self
+                    .dangerous_ingredients()
+                    .map_err(|e| SolveError::no_solution(e.to_string()))?
+                    .into_values()
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                Ok(Solved::new(
+                    dangerous.as_str(),
+                    format!("canonical dangerous ingredient list is {}", dangerous),
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Answer;
+    use indoc::indoc;
+
+    use super::*;
+
+    const EXAMPLE: &str = indoc!(
+        "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+        trh fvjkl sbzzf mxmxvkd (contains dairy)
+        sqjhc fvjkl (contains soy)
+        sqjhc mxmxvkd sbzzf (contains fish)"
+    );
+
+    #[test]
+    fn test_example() {
+        let solution = EXAMPLE.parse::<Solution>().unwrap();
+
+        assert_eq!(solution.safe_appearances(), 5);
+        assert_eq!(
+            solution.solve_typed(Part::Two).unwrap().answer,
+            Answer::from("mxmxvkd,sqjhc,fvjkl")
+        );
+    }
+
+    #[test]
+    fn test_ambiguous() {
+        let solution = indoc!(
+            "mxmxvkd sqjhc (contains dairy, fish)
+            sqjhc mxmxvkd sbzzf (contains fish)"
+        )
+        .parse::<Solution>()
+        .unwrap();
+
+        assert_eq!(
+            solution.dangerous_ingredients(),
+            Err(AssignmentError::Ambiguous(vec![
+                "dairy".to_owned(),
+                "fish".to_owned()
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "mxmxvkd (contains dairy\n".parse::<Solution>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 9: Allergens list is not closed (in \"mxmxvkd (contains dairy\")"
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use std::{collections::VecDeque, fmt};
+
+pub(crate) type Card = u32;
+
+/// Bases of the two polynomial deck hashes, odd so that they're invertible modulo 2^64
+const HASH_BASES: (u64, u64) = (0x9e37_79b9_7f4a_7c15, 0xc2b2_ae3d_27d4_eb4f);
+
+/// Multiplicative inverse of an odd `value` modulo 2^64, by Newton's iteration
+const fn inverse(value: u64) -> u64 {
+    // correct to 3 bits, every iteration doubles that
+    let mut inverse = value;
+    let mut iteration = 0;
+    while iteration < 5 {
+        inverse = inverse.wrapping_mul(2u64.wrapping_sub(value.wrapping_mul(inverse)));
+        iteration += 1;
+    }
+
+    inverse
+}
+
+/// Sum of `card * BASE^position` over the cards, counting positions from the bottom,
+/// updated as cards are drawn from the top and put at the bottom
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct RollingHash<const BASE: u64> {
+    hash: u64,
+    /// `BASE^len`
+    power: u64,
+}
+
+impl<const BASE: u64> RollingHash<BASE> {
+    const INVERSE: u64 = inverse(BASE);
+    const EMPTY: Self = Self { hash: 0, power: 1 };
+
+    fn pop_front(&mut self, card: Card) {
+        self.power = self.power.wrapping_mul(Self::INVERSE);
+        self.hash = self
+            .hash
+            .wrapping_sub(u64::from(card).wrapping_mul(self.power));
+    }
+
+    fn push_back(&mut self, card: Card) {
+        self.hash = self.hash.wrapping_mul(BASE).wrapping_add(u64::from(card));
+        self.power = self.power.wrapping_mul(BASE);
+    }
+}
+
+/// Fixed-size summary of a deck, equal for equal decks whatever their size
+pub(crate) type Fingerprint = u128;
+
+/// Deck of cards which keeps a 128-bit rolling hash of its contents, so that snapshots
+/// of it don't need to copy or go through the cards
+#[derive(Debug, Clone)]
+pub(crate) struct Deck {
+    cards: VecDeque<Card>,
+    hashes: (RollingHash<{ HASH_BASES.0 }>, RollingHash<{ HASH_BASES.1 }>),
+}
+
+impl Deck {
+    pub(crate) fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    pub(crate) fn fingerprint(&self) -> Fingerprint {
+        let (first, second) = self.hashes;
+
+        Fingerprint::from(first.hash) << 64 | Fingerprint::from(second.hash)
+    }
+
+    pub(crate) fn draw(&mut self) -> Option<Card> {
+        let card = self.cards.pop_front()?;
+
+        self.hashes.0.pop_front(card);
+        self.hashes.1.pop_front(card);
+
+        Some(card)
+    }
+
+    pub(crate) fn put(&mut self, card: Card) {
+        self.cards.push_back(card);
+
+        self.hashes.0.push_back(card);
+        self.hashes.1.push_back(card);
+    }
+
+    /// New deck of `count` cards from the top of this one
+    pub(crate) fn copy_top(&self, count: usize) -> Self {
+        self.cards.iter().take(count).copied().collect()
+    }
+
+    /// Cards multiplied by their position counting from the bottom, starting from 1
+    pub(crate) fn score(&self) -> u64 {
+        self.cards
+            .iter()
+            .rev()
+            .zip(1..)
+            .map(|(&card, position)| u64::from(card) * position)
+            .sum()
+    }
+}
+
+impl FromIterator<Card> for Deck {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> Self {
+        let mut deck = Self {
+            cards: VecDeque::new(),
+            hashes: (RollingHash::EMPTY, RollingHash::EMPTY),
+        };
+        cards.into_iter().for_each(|card| deck.put(card));
+
+        deck
+    }
+}
+
+impl fmt::Display for Deck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cards = self
+            .cards
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(f, "{}", cards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inverse() {
+        assert_eq!(
+            HASH_BASES
+                .0
+                .wrapping_mul(RollingHash::<{ HASH_BASES.0 }>::INVERSE),
+            1
+        );
+        assert_eq!(
+            HASH_BASES
+                .1
+                .wrapping_mul(RollingHash::<{ HASH_BASES.1 }>::INVERSE),
+            1
+        );
+    }
+
+    #[test]
+    fn test_rolling_hash() {
+        let mut deck = [9, 2, 6].into_iter().collect::<Deck>();
+
+        assert_eq!(deck.draw(), Some(9));
+        deck.put(9);
+        deck.put(1);
+
+        let rebuilt = [2, 6, 9, 1].into_iter().collect::<Deck>();
+
+        assert_eq!(deck.fingerprint(), rebuilt.fingerprint());
+        assert_eq!(deck.to_string(), "2, 6, 9, 1");
+        assert_ne!(
+            deck.fingerprint(),
+            [2, 6, 1, 9].into_iter().collect::<Deck>().fingerprint()
+        );
+        assert_eq!(
+            deck.copy_top(2).fingerprint(),
+            [2, 6].into_iter().collect::<Deck>().fingerprint()
+        );
+
+        while deck.draw().is_some() {}
+
+        assert_eq!(deck.fingerprint(), 0);
+        assert!(deck.is_empty());
+    }
+
+    #[test]
+    fn test_score() {
+        let deck = [3, 2, 10, 6, 8, 5, 9, 4, 7, 1]
+            .into_iter()
+            .collect::<Deck>();
+
+        assert_eq!(deck.score(), 306);
+    }
+}
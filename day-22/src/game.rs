@@ -0,0 +1,347 @@
+use std::{collections::HashMap, fmt};
+
+use displaydoc::Display;
+use thiserror::Error;
+
+use crate::deck::{Deck, Fingerprint};
+
+/// Snapshot of both decks remembered every round; the total number of cards never changes,
+/// so the first deck's length tells how they're split
+type State = (Fingerprint, Fingerprint, usize);
+
+fn state([first, second]: &[Deck; 2]) -> State {
+    (first.fingerprint(), second.fingerprint(), first.len())
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Rules {
+    Combat,
+    /// Rounds may be decided by sub-games, repeated states end the game
+    RecursiveCombat,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Player {
+    One,
+    Two,
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// game never ends: round {round} repeats round {earlier}
+#[derive(Debug, Display, Error, PartialEq)]
+pub(crate) struct EndlessGame {
+    round: usize,
+    earlier: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct Outcome {
+    pub(crate) winner: Player,
+    pub(crate) deck: Deck,
+}
+
+#[derive(Debug)]
+pub(crate) struct Game {
+    rules: Rules,
+    /// Round-by-round log worded like the puzzle text, if requested
+    trace: Option<String>,
+    games_played: usize,
+}
+
+impl Game {
+    pub(crate) fn new(rules: Rules) -> Self {
+        Self {
+            rules,
+            trace: None,
+            games_played: 0,
+        }
+    }
+
+    pub(crate) fn traced(rules: Rules) -> Self {
+        Self {
+            trace: Some(String::new()),
+            ..Self::new(rules)
+        }
+    }
+
+    pub(crate) fn trace(&self) -> Option<&str> {
+        self.trace.as_deref()
+    }
+
+    fn log(&mut self, line: fmt::Arguments<'_>) {
+        if let Some(trace) = &mut self.trace {
+            trace.push_str(line.to_string().trim_end_matches(' '));
+            trace.push('\n');
+        }
+    }
+
+    /// Plays a game to the end, which plain Combat might never reach
+    pub(crate) fn play(&mut self, first: Deck, second: Deck) -> Result<Outcome, EndlessGame> {
+        let (winner, [first, second]) = self.play_game([first, second])?;
+
+        self.log(format_args!("== Post-game results =="));
+        self.log(format_args!("Player 1's deck: {}", first));
+        self.log(format_args!("Player 2's deck: {}", second));
+
+        let deck = match winner {
+            Player::One => first,
+            Player::Two => second,
+        };
+
+        Ok(Outcome { winner, deck })
+    }
+
+    fn play_game(&mut self, mut decks: [Deck; 2]) -> Result<(Player, [Deck; 2]), EndlessGame> {
+        self.games_played += 1;
+        let game = self.games_played;
+        let recursive = self.rules == Rules::RecursiveCombat;
+
+        if recursive {
+            self.log(format_args!("=== Game {} ===\n", game));
+        }
+
+        let mut seen = HashMap::<State, usize>::new();
+        let winner = (1..)
+            .find_map(|round| {
+                if let Some(earlier) = seen.insert(state(&decks), round) {
+                    if recursive {
+                        return Some(Ok(Player::One));
+                    }
+
+                    // without the recursive rule nothing stops the same rounds from repeating
+                    self.log(format_args!(
+                        "-- Round {} repeats round {} --",
+                        round, earlier
+                    ));
+                    return Some(Err(EndlessGame { round, earlier }));
+                }
+
+                let [first, second] = &mut decks;
+                if first.is_empty() {
+                    return Some(Ok(Player::Two));
+                }
+                if second.is_empty() {
+                    return Some(Ok(Player::One));
+                }
+
+                if recursive {
+                    self.log(format_args!("-- Round {} (Game {}) --", round, game));
+                } else {
+                    self.log(format_args!("-- Round {} --", round));
+                }
+                self.log(format_args!("Player 1's deck: {}", first));
+                self.log(format_args!("Player 2's deck: {}", second));
+
+                let first_card = first.draw().expect("deck is not empty");
+                let second_card = second.draw().expect("deck is not empty");
+                self.log(format_args!("Player 1 plays: {}", first_card));
+                self.log(format_args!("Player 2 plays: {}", second_card));
+
+                let sub_game = recursive
+                    && first.len() >= first_card as usize
+                    && second.len() >= second_card as usize;
+                let round_winner = if sub_game {
+                    self.log(format_args!(
+                        "Playing a sub-game to determine the winner...\n"
+                    ));
+                    let sub_decks = [
+                        first.copy_top(first_card as usize),
+                        second.copy_top(second_card as usize),
+                    ];
+                    let winner = match self.play_game(sub_decks) {
+                        Ok((winner, _)) => winner,
+                        Err(e) => return Some(Err(e)),
+                    };
+                    self.log(format_args!("...anyway, back to game {}.", game));
+
+                    winner
+                } else if first_card > second_card {
+                    Player::One
+                } else {
+                    Player::Two
+                };
+
+                if recursive {
+                    self.log(format_args!(
+                        "Player {} wins round {} of game {}!\n",
+                        round_winner, round, game
+                    ));
+                } else {
+                    self.log(format_args!("Player {} wins the round!\n", round_winner));
+                }
+
+                let [first, second] = &mut decks;
+                match round_winner {
+                    Player::One => {
+                        first.put(first_card);
+                        first.put(second_card);
+                    }
+                    Player::Two => {
+                        second.put(second_card);
+                        second.put(first_card);
+                    }
+                }
+
+                None
+            })
+            .expect("rounds are endless")?;
+
+        if recursive {
+            self.log(format_args!(
+                "The winner of game {} is player {}!\n",
+                game, winner
+            ));
+        }
+
+        Ok((winner, decks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    fn decks() -> (Deck, Deck) {
+        (
+            [9, 2, 6, 3, 1].into_iter().collect(),
+            [5, 8, 4, 7, 10].into_iter().collect(),
+        )
+    }
+
+    #[test]
+    fn test_combat() {
+        let (first, second) = decks();
+        let mut game = Game::traced(Rules::Combat);
+        let outcome = game.play(first, second).unwrap();
+
+        assert_eq!(outcome.winner, Player::Two);
+        assert_eq!(outcome.deck.score(), 306);
+
+        let trace = game.trace().unwrap();
+
+        assert!(trace.starts_with(indoc!(
+            "-- Round 1 --
+            Player 1's deck: 9, 2, 6, 3, 1
+            Player 2's deck: 5, 8, 4, 7, 10
+            Player 1 plays: 9
+            Player 2 plays: 5
+            Player 1 wins the round!
+
+            -- Round 2 --
+            "
+        )));
+        assert!(trace.ends_with(indoc!(
+            "-- Round 29 --
+            Player 1's deck: 1
+            Player 2's deck: 7, 3, 2, 10, 6, 8, 5, 9, 4
+            Player 1 plays: 1
+            Player 2 plays: 7
+            Player 2 wins the round!
+
+            == Post-game results ==
+            Player 1's deck:
+            Player 2's deck: 3, 2, 10, 6, 8, 5, 9, 4, 7, 1
+            "
+        )));
+    }
+
+    #[test]
+    fn test_recursive_combat() {
+        let (first, second) = decks();
+        let mut game = Game::traced(Rules::RecursiveCombat);
+        let outcome = game.play(first, second).unwrap();
+
+        assert_eq!(outcome.winner, Player::Two);
+        assert_eq!(outcome.deck.score(), 291);
+
+        let trace = game.trace().unwrap();
+
+        assert!(trace.contains(indoc!(
+            "-- Round 9 (Game 1) --
+            Player 1's deck: 4, 9, 8, 5, 2
+            Player 2's deck: 3, 10, 1, 7, 6
+            Player 1 plays: 4
+            Player 2 plays: 3
+            Playing a sub-game to determine the winner...
+
+            === Game 2 ===
+
+            -- Round 1 (Game 2) --
+            Player 1's deck: 9, 8, 5, 2
+            Player 2's deck: 10, 1, 7
+            Player 1 plays: 9
+            Player 2 plays: 10
+            Player 2 wins round 1 of game 2!
+            "
+        )));
+        assert!(trace.ends_with(indoc!(
+            "The winner of game 1 is player 2!
+
+            == Post-game results ==
+            Player 1's deck:
+            Player 2's deck: 7, 5, 6, 2, 4, 1, 10, 8, 9, 3
+            "
+        )));
+        // game numbers keep increasing through sub-games of sub-games
+        assert!(trace.contains("=== Game 5 ==="));
+    }
+
+    #[test]
+    fn test_state_size() {
+        let small = [[1].into_iter().collect(), [2].into_iter().collect()];
+        let large = [(1..5000).collect(), (5000..10000).collect()];
+
+        // remembering a round costs the same for any deck size, the cards aren't copied
+        assert_eq!(
+            std::mem::size_of_val(&state(&small)),
+            std::mem::size_of_val(&state(&large))
+        );
+        assert_ne!(state(&small), state(&large));
+    }
+
+    #[test]
+    fn test_infinite_game() {
+        let mut game = Game::new(Rules::RecursiveCombat);
+        let outcome = game
+            .play(
+                [43, 19].into_iter().collect(),
+                [2, 29, 14].into_iter().collect(),
+            )
+            .unwrap();
+
+        assert_eq!(outcome.winner, Player::One);
+        assert_eq!(game.trace(), None);
+
+        let mut game = Game::traced(Rules::Combat);
+        let e = game
+            .play(
+                [43, 19].into_iter().collect(),
+                [2, 29, 14].into_iter().collect(),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            e,
+            EndlessGame {
+                round: 7,
+                earlier: 1
+            }
+        );
+        assert!(game.trace().unwrap().ends_with(indoc!(
+            "Player 2 wins the round!
+
+            -- Round 7 repeats round 1 --
+            "
+        )));
+    }
+}
@@ -0,0 +1,206 @@
+use std::{error::Error, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{ParseError, SolveError, Solved, TypedSolver};
+use displaydoc::Display;
+use thiserror::Error;
+
+mod deck;
+mod game;
+
+use deck::Deck;
+use game::{Game, Rules};
+
+#[derive(Debug)]
+pub struct Solution {
+    first: Deck,
+    second: Deck,
+}
+
+#[derive(Debug, Display, Error)]
+/// Error while parsing decks
+enum DecksParseError {
+    /// Expected "Player {0}:"
+    InvalidHeader(usize),
+    /// Expected decks of 2 players, got {0}
+    PlayerCount(usize),
+}
+
+fn parse_deck(lines: &[(usize, &str)], player: usize) -> Result<Deck, ParseError> {
+    let (&(number, header), cards) = lines.split_first().expect("deck lines are not empty");
+
+    if header != format!("Player {}:", player) {
+        return Err(ParseError::new(
+            number,
+            header,
+            DecksParseError::InvalidHeader(player),
+        ));
+    }
+
+    cards
+        .iter()
+        .map(|&(number, card)| {
+            card.parse()
+                .map_err(|e| ParseError::located(number, card, e))
+        })
+        .collect()
+}
+
+impl FromStr for Solution {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input_data: &str) -> Result<Self, Self::Err> {
+        let eof = input_data.lines().count() + 1;
+        let lines = input_data
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| (idx + 1, line))
+            .collect::<Vec<_>>();
+
+        let mut decks = lines
+            .split(|(_, line)| line.is_empty())
+            .filter(|block| !block.is_empty())
+            .zip(1..)
+            .map(|(block, player)| parse_deck(block, player))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if decks.len() != 2 {
+            return Err(ParseError::new(eof, "", DecksParseError::PlayerCount(decks.len())).into());
+        }
+
+        let second = decks.pop().expect("two decks");
+        let first = decks.pop().expect("two decks");
+
+        Ok(Self { first, second })
+    }
+}
+
+impl Solution {
+    fn rules(part: Part) -> Rules {
+        match part {
+            Part::One => Rules::Combat,
+            Part::Two => Rules::RecursiveCombat,
+        }
+    }
+
+    /// Round-by-round log of the game for `part`, worded like the puzzle text
+    pub fn trace(&self, part: Part) -> String {
+        let mut game = Game::traced(Self::rules(part));
+        // an endless game is traced up to the first repeated round
+        let _ = game.play(self.first.clone(), self.second.clone());
+
+        game.trace().unwrap_or_default().to_owned()
+    }
+}
+
+impl Solver for Solution {
+    fn day_number() -> u32 {
+        22
+    }
+
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        let outcome = Game::new(Self::rules(part))
+            .play(self.first.clone(), self.second.clone())
+            .map_err(|e| SolveError::no_solution(e.to_string()))?;
+        let score = outcome.deck.score();
+
+        Ok(Solved::new(
+            score,
+            format!("player {} wins with score {}", outcome.winner, score),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Answer;
+    use indoc::indoc;
+
+    use super::*;
+
+    const EXAMPLE: &str = indoc!(
+        "Player 1:
+        9
+        2
+        6
+        3
+        1
+
+        Player 2:
+        5
+        8
+        4
+        7
+        10"
+    );
+
+    #[test]
+    fn test_example() {
+        let solution = EXAMPLE.parse::<Solution>().unwrap();
+
+        assert_eq!(
+            solution.solve_typed(Part::One).unwrap().answer,
+            Answer::from(306u64)
+        );
+        assert_eq!(
+            solution.solve_typed(Part::Two).unwrap().message,
+            "player 2 wins with score 291"
+        );
+        assert!(solution
+            .trace(Part::Two)
+            .starts_with("=== Game 1 ===\n\n-- Round 1 (Game 1) --\n"));
+    }
+
+    #[test]
+    fn test_endless_game() {
+        let solution = "Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14"
+            .parse::<Solution>()
+            .unwrap();
+
+        assert_eq!(
+            solution.solve_typed(Part::One),
+            Err(SolveError::no_solution(
+                "game never ends: round 7 repeats round 1"
+            ))
+        );
+        assert_eq!(
+            solution.solve_typed(Part::Two).unwrap().message,
+            "player 1 wins with score 105"
+        );
+        assert!(solution
+            .trace(Part::One)
+            .ends_with("-- Round 7 repeats round 1 --\n"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "Player 1:\n1\n\nPlayer 3:\n2"
+            .parse::<Solution>()
+            .unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 4: Expected \"Player 2:\" (in \"Player 3:\")"
+        );
+
+        let e = "Player 1:\n1\nx".parse::<Solution>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 3: invalid digit found in string (in \"x\")"
+        );
+
+        let e = "Player 1:\n1\n".parse::<Solution>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 3: Expected decks of 2 players, got 1 (in \"\")"
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use displaydoc::Display;
+use thiserror::Error;
+
+pub type Cup = u32;
+
+/// Number of cups the crab picks up every move
+const PICKED_UP: usize = 3;
+
+#[derive(Debug, Display, Error, PartialEq, Eq)]
+pub enum CupsError {
+    /// At least {min} cups are needed to play, got {got}
+    TooFewCups { min: usize, got: usize },
+    /// Can't fit {labelled} labelled cups into a ring of {cup_count}
+    RingTooSmall { labelled: usize, cup_count: usize },
+    /// Cup {cup} is out of range, labels must go from 1 up to {count}
+    LabelOutOfRange { cup: Cup, count: usize },
+    /// Cup {0} is labelled twice
+    DuplicateCup(Cup),
+}
+
+/// Ring of cups labelled `1..=len`, stored as the label of the next cup for every label,
+/// so that moving cups around is O(1)
+#[derive(Debug, Clone)]
+pub struct Cups {
+    /// `next[label]` is the label of the cup clockwise of `label`, `next[0]` is unused
+    next: Vec<Cup>,
+    current: Cup,
+}
+
+impl Cups {
+    /// Ring starting with `labels` (a permutation of `1..=labels.len()`) in clockwise order,
+    /// followed by the cups labelled from `labels.len() + 1` to `cup_count`
+    pub fn new(labels: &[Cup], cup_count: usize) -> Result<Self, CupsError> {
+        let min = PICKED_UP + 2;
+        if cup_count < min {
+            return Err(CupsError::TooFewCups {
+                min,
+                got: cup_count,
+            });
+        }
+        if cup_count < labels.len() {
+            return Err(CupsError::RingTooSmall {
+                labelled: labels.len(),
+                cup_count,
+            });
+        }
+
+        // `labels.len()` distinct labels in range are a permutation of them
+        let mut seen = vec![false; labels.len() + 1];
+        for &cup in labels {
+            match seen.get_mut(cup as usize) {
+                Some(seen) if cup > 0 => {
+                    if std::mem::replace(seen, true) {
+                        return Err(CupsError::DuplicateCup(cup));
+                    }
+                }
+                _ => {
+                    return Err(CupsError::LabelOutOfRange {
+                        cup,
+                        count: labels.len(),
+                    })
+                }
+            }
+        }
+
+        let order = labels
+            .iter()
+            .copied()
+            .chain(labels.len() as Cup + 1..=cup_count as Cup);
+
+        let mut next = vec![0; cup_count + 1];
+        let mut previous = None;
+        let mut first = None;
+        for cup in order {
+            match previous {
+                Some(previous) => next[previous as usize] = cup,
+                None => first = Some(cup),
+            }
+            previous = Some(cup);
+        }
+
+        let (first, last) = first.zip(previous).expect("ring is not empty");
+        next[last as usize] = first;
+
+        Ok(Self {
+            next,
+            current: first,
+        })
+    }
+
+    fn len(&self) -> Cup {
+        self.next.len() as Cup - 1
+    }
+
+    fn next(&self, cup: Cup) -> Cup {
+        self.next[cup as usize]
+    }
+
+    fn play_move(&mut self) {
+        let first_picked = self.next(self.current);
+        let mut picked = [first_picked; PICKED_UP];
+        for idx in 1..PICKED_UP {
+            picked[idx] = self.next(picked[idx - 1]);
+        }
+        let last_picked = picked[PICKED_UP - 1];
+
+        let mut destination = self.current;
+        loop {
+            destination = if destination == 1 {
+                self.len()
+            } else {
+                destination - 1
+            };
+
+            if !picked.contains(&destination) {
+                break;
+            }
+        }
+
+        self.next[self.current as usize] = self.next(last_picked);
+        self.next[last_picked as usize] = self.next(destination);
+        self.next[destination as usize] = first_picked;
+
+        self.current = self.next(self.current);
+    }
+
+    pub fn play(&mut self, moves: usize) {
+        for _ in 0..moves {
+            self.play_move();
+        }
+    }
+
+    /// Labels of the cups clockwise after cup 1, up to `count` of them
+    pub fn after_one(&self, count: usize) -> impl Iterator<Item = Cup> + '_ {
+        std::iter::successors(Some(self.next(1)), move |&cup| Some(self.next(cup)))
+            .take_while(|&cup| cup != 1)
+            .take(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels_after_one(cups: &Cups) -> String {
+        cups.after_one(usize::MAX)
+            .map(|cup| cup.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_example() {
+        let labels = [3, 8, 9, 1, 2, 5, 4, 6, 7];
+        let mut cups = Cups::new(&labels, labels.len()).unwrap();
+
+        assert_eq!(labels_after_one(&cups), "25467389");
+
+        cups.play(1);
+
+        // 3 (8 9 1) 2 5 4 6 7 -> 3 2 8 9 1 5 4 6 7
+        assert_eq!(labels_after_one(&cups), "54673289");
+
+        cups.play(9);
+
+        assert_eq!(labels_after_one(&cups), "92658374");
+
+        cups.play(90);
+
+        assert_eq!(labels_after_one(&cups), "67384529");
+    }
+
+    #[test]
+    fn test_extended_ring() {
+        let mut cups = Cups::new(&[3, 1, 2], 6).unwrap();
+
+        assert_eq!(labels_after_one(&cups), "24563");
+
+        // 3 (1 2 4) 5 6 -> 3 5 6 1 2 4, wrapping around to the highest label
+        cups.play(1);
+
+        assert_eq!(labels_after_one(&cups), "24356");
+        assert_eq!(cups.after_one(2).collect::<Vec<_>>(), [2, 4]);
+    }
+
+    #[test]
+    fn test_invalid_rings() {
+        assert_eq!(
+            Cups::new(&[1, 2, 3, 4], 4).unwrap_err(),
+            CupsError::TooFewCups { min: 5, got: 4 }
+        );
+        assert_eq!(
+            Cups::new(&[1, 2, 3, 4, 5, 6], 5).unwrap_err().to_string(),
+            "Can't fit 6 labelled cups into a ring of 5"
+        );
+    }
+
+    #[test]
+    fn test_invalid_labels() {
+        assert_eq!(
+            Cups::new(&[7], 5).unwrap_err(),
+            CupsError::LabelOutOfRange { cup: 7, count: 1 }
+        );
+        assert_eq!(
+            Cups::new(&[2, 0, 1], 5).unwrap_err().to_string(),
+            "Cup 0 is out of range, labels must go from 1 up to 3"
+        );
+        assert_eq!(
+            Cups::new(&[1, 1, 2, 3, 4], 5).unwrap_err(),
+            CupsError::DuplicateCup(1)
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use std::{error::Error, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{Located, ParseError, SolveError, Solved, TypedSolver};
+use displaydoc::Display;
+use thiserror::Error;
+
+pub mod cups;
+
+use cups::{Cup, Cups, CupsError};
+
+const PART_ONE_MOVES: usize = 100;
+const PART_TWO_CUPS: usize = 1_000_000;
+const PART_TWO_MOVES: usize = 10_000_000;
+
+#[derive(Debug)]
+pub struct Solution {
+    labels: Vec<Cup>,
+}
+
+#[derive(Debug, Display, Error)]
+/// Error while parsing cup labels
+pub enum LabelsParseError {
+    /// No cups given
+    Empty,
+    /// Invalid cup label {chr:?}
+    InvalidLabel { chr: char, offset: usize },
+    /// Cup {cup} is listed twice
+    DuplicateCup { cup: Cup, offset: usize },
+    /// Cup {0} is missing, labels must go from 1 up to the number of cups
+    MissingCup(Cup),
+}
+
+impl Located for LabelsParseError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::Empty | Self::MissingCup(_) => None,
+            Self::InvalidLabel { offset, .. } | Self::DuplicateCup { offset, .. } => Some(offset),
+        }
+    }
+}
+
+fn parse_labels(line: &str) -> Result<Vec<Cup>, LabelsParseError> {
+    let labels = line
+        .chars()
+        .enumerate()
+        .map(|(offset, chr)| match chr.to_digit(10) {
+            Some(label) if label > 0 => Ok(label),
+            _ => Err(LabelsParseError::InvalidLabel { chr, offset }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if labels.is_empty() {
+        return Err(LabelsParseError::Empty);
+    }
+
+    let mut seen = [false; 10];
+    for (offset, &cup) in labels.iter().enumerate() {
+        if std::mem::replace(&mut seen[cup as usize], true) {
+            return Err(LabelsParseError::DuplicateCup { cup, offset });
+        }
+    }
+    if let Some(missing) = (1..=labels.len() as Cup).find(|&cup| !seen[cup as usize]) {
+        return Err(LabelsParseError::MissingCup(missing));
+    }
+
+    Ok(labels)
+}
+
+impl FromStr for Solution {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input_data: &str) -> Result<Self, Self::Err> {
+        let line = input_data.lines().next().unwrap_or_default();
+        let labels = parse_labels(line).map_err(|e| ParseError::located(1, line, e))?;
+
+        Ok(Self { labels })
+    }
+}
+
+impl Solution {
+    /// Plays `moves` moves with the labelled cups followed by more up to `cup_count`
+    pub fn play(&self, cup_count: usize, moves: usize) -> Result<Cups, CupsError> {
+        let mut cups = Cups::new(&self.labels, cup_count)?;
+        cups.play(moves);
+
+        Ok(cups)
+    }
+}
+
+impl Solver for Solution {
+    fn day_number() -> u32 {
+        23
+    }
+
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        let no_solution = |e: CupsError| SolveError::no_solution(e.to_string());
+
+        match part {
+            Part::One => {
+                let cups = self
+                    .play(self.labels.len(), PART_ONE_MOVES)
+                    .map_err(no_solution)?;
+                let labels = cups
+                    .after_one(self.labels.len())
+                    .map(|cup| cup.to_string())
+                    .collect::<String>();
+
+                Ok(Solved::new(
+                    labels.as_str(),
+                    format!("labels after cup 1 are {}", labels),
+                ))
+            }
+            Part::Two => {
+                let cups = self
+                    .play(PART_TWO_CUPS, PART_TWO_MOVES)
+                    .map_err(no_solution)?;
+                let product = cups.after_one(2).map(u64::from).product::<u64>();
+
+                Ok(Solved::new(
+                    product,
+                    format!("product of labels of two cups after cup 1 is {}", product),
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Answer;
+
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let solution = "389125467".parse::<Solution>().unwrap();
+
+        assert_eq!(
+            solution.solve_typed(Part::One).unwrap().answer,
+            Answer::from("67384529")
+        );
+
+        let cups = solution.play(9, 10).unwrap();
+
+        assert_eq!(
+            cups.after_one(8).collect::<Vec<_>>(),
+            [9, 2, 6, 5, 8, 3, 7, 4]
+        );
+    }
+
+    #[test]
+    fn test_million_cups() {
+        let solution = "389125467".parse::<Solution>().unwrap();
+
+        assert_eq!(
+            solution.solve_typed(Part::Two).unwrap().answer,
+            Answer::from(149245887792u64)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        macro_rules! assert_error_eq {
+            ($input: expr, $message: expr) => {
+                assert_eq!(
+                    $input.parse::<Solution>().unwrap_err().to_string(),
+                    $message
+                );
+            };
+        }
+
+        assert_error_eq!(
+            "3891x",
+            "line 1, column 5: Invalid cup label 'x' (in \"3891x\")"
+        );
+        assert_error_eq!(
+            "38912534",
+            "line 1, column 7: Cup 3 is listed twice (in \"38912534\")"
+        );
+        assert_error_eq!(
+            "3912",
+            "line 1: Cup 4 is missing, labels must go from 1 up to the number of cups (in \"3912\")"
+        );
+        assert_error_eq!("", "line 1: No cups given (in \"\")");
+    }
+}
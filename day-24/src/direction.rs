@@ -0,0 +1,181 @@
+use std::{ops::Add, str::FromStr};
+
+use common::Located;
+use displaydoc::Display;
+use thiserror::Error;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl Direction {
+    pub(crate) const ALL: [Self; 6] = [
+        Self::East,
+        Self::SouthEast,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+        Self::NorthEast,
+    ];
+}
+
+/// Tile position in axial coordinates: `q` grows to the east, `r` to the south-east
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub(crate) struct Hex {
+    q: i32,
+    r: i32,
+}
+
+impl Hex {
+    pub(crate) fn neighbours(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .iter()
+            .map(move |&direction| self + direction)
+    }
+}
+
+impl Add<Direction> for Hex {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        let (dq, dr) = match direction {
+            Direction::East => (1, 0),
+            Direction::SouthEast => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (0, -1),
+            Direction::NorthEast => (1, -1),
+        };
+
+        Self {
+            q: self.q + dq,
+            r: self.r + dr,
+        }
+    }
+}
+
+/// Directions from the reference tile, written without delimiters, e.g. `esenee`
+#[derive(Debug, Clone)]
+pub(crate) struct Path(Vec<Direction>);
+
+#[derive(Debug, Display, Error)]
+pub(crate) enum ParsePathError {
+    /// String is empty
+    EmptyStr,
+    /// Invalid direction: {chr}
+    InvalidDirection { chr: char, offset: usize },
+    /// Direction {chr:?} must be followed by 'e' or 'w'
+    IncompleteDirection { chr: char, offset: usize },
+}
+
+impl Located for ParsePathError {
+    fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::EmptyStr => None,
+            Self::InvalidDirection { offset, .. } | Self::IncompleteDirection { offset, .. } => {
+                Some(offset)
+            }
+        }
+    }
+}
+
+impl FromStr for Path {
+    type Err = ParsePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParsePathError::EmptyStr);
+        }
+
+        let mut chars = s.chars().enumerate();
+        let mut directions = Vec::new();
+
+        while let Some((offset, chr)) = chars.next() {
+            let direction = match chr {
+                'e' => Direction::East,
+                'w' => Direction::West,
+                'n' | 's' => match (chr, chars.next()) {
+                    ('n', Some((_, 'e'))) => Direction::NorthEast,
+                    ('n', Some((_, 'w'))) => Direction::NorthWest,
+                    ('s', Some((_, 'e'))) => Direction::SouthEast,
+                    ('s', Some((_, 'w'))) => Direction::SouthWest,
+                    _ => return Err(ParsePathError::IncompleteDirection { chr, offset }),
+                },
+                chr => return Err(ParsePathError::InvalidDirection { chr, offset }),
+            };
+
+            directions.push(direction);
+        }
+
+        Ok(Self(directions))
+    }
+}
+
+impl Path {
+    /// Tile reached by following the path from the reference tile
+    pub(crate) fn destination(&self) -> Hex {
+        self.0
+            .iter()
+            .fold(Hex::default(), |hex, &direction| hex + direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn destination(path: &str) -> Hex {
+        path.parse::<Path>().unwrap().destination()
+    }
+
+    #[test]
+    fn test_destination() {
+        assert_eq!(destination("nwwswee"), Hex::default());
+        assert_eq!(destination("esew"), Hex::default() + Direction::SouthEast);
+        assert_eq!(
+            destination("nenwsewsw"),
+            destination("sw") + Direction::NorthWest
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let origin = Hex::default();
+
+        assert!(origin
+            .neighbours()
+            .all(|neighbour| neighbour.neighbours().any(|hex| hex == origin)));
+        assert_eq!(origin.neighbours().count(), 6);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        macro_rules! assert_error_eq {
+            ($path: expr, $message: expr, $offset: expr) => {
+                let e = $path.parse::<Path>().unwrap_err();
+
+                assert_eq!(e.to_string(), $message);
+                assert_eq!(e.offset(), $offset);
+            };
+        }
+
+        assert_error_eq!(
+            "esenx",
+            "Direction 'n' must be followed by 'e' or 'w'",
+            Some(3)
+        );
+        assert_error_eq!(
+            "ees",
+            "Direction 's' must be followed by 'e' or 'w'",
+            Some(2)
+        );
+        assert_error_eq!("eeNe", "Invalid direction: N", Some(2));
+        assert_error_eq!("", "String is empty", None);
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::direction::Hex;
+
+/// Hex tiled floor, every tile is white unless it's listed as black
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Floor {
+    black: HashSet<Hex>,
+}
+
+impl Floor {
+    pub(crate) fn flip(&mut self, tile: Hex) {
+        if !self.black.remove(&tile) {
+            self.black.insert(tile);
+        }
+    }
+
+    pub(crate) fn black_count(&self) -> usize {
+        self.black.len()
+    }
+
+    /// Flips tiles for a day: black ones with zero or more than two black neighbours
+    /// turn white, white ones with exactly two black neighbours turn black
+    pub(crate) fn next_day(&mut self) {
+        let mut black_neighbours = HashMap::<_, u8>::new();
+        for neighbour in self.black.iter().flat_map(|tile| tile.neighbours()) {
+            *black_neighbours.entry(neighbour).or_default() += 1;
+        }
+
+        self.black = black_neighbours
+            .into_iter()
+            .filter(|(tile, count)| match count {
+                1 => self.black.contains(tile),
+                2 => true,
+                _ => false,
+            })
+            .map(|(tile, _)| tile)
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direction::Direction;
+
+    #[test]
+    fn test_flip() {
+        let mut floor = Floor::default();
+        let tile = Hex::default() + Direction::East;
+
+        floor.flip(tile);
+        floor.flip(Hex::default());
+        floor.flip(tile);
+
+        assert_eq!(floor.black_count(), 1);
+    }
+
+    #[test]
+    fn test_next_day() {
+        let mut floor = Floor::default();
+
+        floor.flip(Hex::default());
+
+        floor.next_day();
+
+        // lonely tile turns white
+        assert_eq!(floor.black_count(), 0);
+
+        floor.flip(Hex::default());
+        floor.flip(Hex::default() + Direction::East);
+
+        floor.next_day();
+
+        // both tiles stay black, their two common neighbours turn black
+        assert_eq!(floor.black_count(), 4);
+        assert!(floor
+            .black
+            .contains(&(Hex::default() + Direction::NorthEast)));
+        assert!(floor
+            .black
+            .contains(&(Hex::default() + Direction::SouthEast)));
+    }
+}
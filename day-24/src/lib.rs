@@ -0,0 +1,126 @@
+use std::{error::Error, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{parse_lines, SolveError, Solved, TypedSolver};
+
+mod direction;
+mod floor;
+
+use direction::Path;
+use floor::Floor;
+
+const EXHIBIT_DAYS: usize = 100;
+
+#[derive(Debug)]
+pub struct Solution {
+    paths: Vec<Path>,
+}
+
+impl FromStr for Solution {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input_data: &str) -> Result<Self, Self::Err> {
+        let paths = parse_lines(input_data)?;
+
+        Ok(Self { paths })
+    }
+}
+
+impl Solution {
+    fn initial_floor(&self) -> Floor {
+        let mut floor = Floor::default();
+        for path in &self.paths {
+            floor.flip(path.destination());
+        }
+
+        floor
+    }
+
+    /// Number of black tiles after `days` of the exhibit
+    pub fn black_tiles_after(&self, days: usize) -> usize {
+        let mut floor = self.initial_floor();
+        (0..days).for_each(|_| floor.next_day());
+
+        floor.black_count()
+    }
+}
+
+impl Solver for Solution {
+    fn day_number() -> u32 {
+        24
+    }
+
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        let days = match part {
+            Part::One => 0,
+            Part::Two => EXHIBIT_DAYS,
+        };
+
+        let black = self.black_tiles_after(days);
+
+        Ok(Solved::new(
+            black,
+            format!("{} tiles are black after {} days", black, days),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Answer;
+    use indoc::indoc;
+
+    use super::*;
+
+    const TILES: &str = indoc!(
+        "swnwwwwwwse
+        esewseneseesw
+        seseeenww
+        seswseneswe
+        nenwwseneneenw
+        neseewnenesee
+        ewsenww
+        nenenwsenew
+        nwsww
+        wneswwsenwwsw
+        nenwwnwwese
+        nenesenw
+        wswwnesweswne
+        newsenwsw
+        wneeesw
+        neswsweeenese"
+    );
+
+    #[test]
+    fn test_exhibit() {
+        let solution = TILES.parse::<Solution>().unwrap();
+
+        assert_eq!(
+            solution.solve_typed(Part::One).unwrap().answer,
+            Answer::from(14usize)
+        );
+        assert_eq!(solution.black_tiles_after(1), 25);
+        assert_eq!(solution.black_tiles_after(2), 20);
+        assert_eq!(solution.black_tiles_after(10), 71);
+        assert_eq!(
+            solution.solve_typed(Part::Two).unwrap().answer,
+            Answer::from(2343usize)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "esew\nnwwswee\nsesenwn\n".parse::<Solution>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 3, column 7: Direction 'n' must be followed by 'e' or 'w' (in \"sesenwn\")"
+        );
+    }
+}
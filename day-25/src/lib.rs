@@ -0,0 +1,178 @@
+use std::{error::Error, num::ParseIntError, str::FromStr};
+
+use advent_utils::{Part, Solver};
+use common::{ParseError, SolveError, Solved, TypedSolver};
+use displaydoc::Display;
+use thiserror::Error;
+
+mod modular;
+
+use modular::{discrete_log, mod_pow};
+
+/// Parameters of the transformation both devices run: `subject^loop_size mod modulus`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Handshake {
+    pub subject: u64,
+    pub modulus: u64,
+}
+
+impl Handshake {
+    pub const DEFAULT: Self = Self {
+        subject: 7,
+        modulus: 20201227,
+    };
+
+    /// Secret loop size which transforms the subject into `public_key`
+    pub fn loop_size(&self, public_key: u64) -> Option<u64> {
+        discrete_log(self.subject, public_key, self.modulus)
+    }
+
+    /// Key derived from the other device's public key and own loop size
+    pub fn encryption_key(&self, public_key: u64, loop_size: u64) -> u64 {
+        mod_pow(public_key, loop_size, self.modulus)
+    }
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    card_key: u64,
+    door_key: u64,
+}
+
+#[derive(Debug, Display, Error)]
+/// Error while parsing public keys
+enum KeysParseError {
+    /// Missing {0} public key
+    MissingKey(&'static str),
+    /// Invalid public key: {0}
+    InvalidKey(#[from] ParseIntError),
+    /// Public key must be less than the modulus {0}
+    KeyOutOfRange(u64),
+}
+
+impl FromStr for Solution {
+    type Err = Box<dyn Error>;
+
+    fn from_str(input_data: &str) -> Result<Self, Self::Err> {
+        let eof = input_data.lines().count() + 1;
+        let mut lines = input_data
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| (idx + 1, line))
+            .filter(|(_, line)| !line.is_empty());
+
+        let mut parse_key = |device| match lines.next() {
+            None => Err(ParseError::new(eof, "", KeysParseError::MissingKey(device))),
+            Some((number, line)) => {
+                let key = line
+                    .parse()
+                    .map_err(|e| ParseError::new(number, line, KeysParseError::InvalidKey(e)))?;
+
+                if key >= Handshake::DEFAULT.modulus {
+                    return Err(ParseError::new(
+                        number,
+                        line,
+                        KeysParseError::KeyOutOfRange(Handshake::DEFAULT.modulus),
+                    ));
+                }
+
+                Ok(key)
+            }
+        };
+
+        Ok(Self {
+            card_key: parse_key("card")?,
+            door_key: parse_key("door")?,
+        })
+    }
+}
+
+impl Solver for Solution {
+    fn day_number() -> u32 {
+        25
+    }
+
+    fn solve(&self, part: Part) -> String {
+        self.describe(part)
+    }
+
+    fn implemented_parts() -> Vec<Part> {
+        vec![Part::One]
+    }
+}
+
+impl TypedSolver for Solution {
+    fn solve_typed(&self, part: Part) -> Result<Solved, SolveError> {
+        if part == Part::Two {
+            return Err(SolveError::NotImplemented(part));
+        }
+
+        let handshake = Handshake::DEFAULT;
+        let card_loop_size = handshake
+            .loop_size(self.card_key)
+            .ok_or_else(|| SolveError::no_solution("card loop size can't be found"))?;
+        let key = handshake.encryption_key(self.door_key, card_loop_size);
+
+        Ok(Solved::new(key, format!("encryption key is {}", key)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Answer;
+
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let handshake = Handshake::DEFAULT;
+
+        assert_eq!(handshake.loop_size(5764801), Some(8));
+        assert_eq!(handshake.loop_size(17807724), Some(11));
+        assert_eq!(handshake.encryption_key(17807724, 8), 14897079);
+        assert_eq!(handshake.encryption_key(5764801, 11), 14897079);
+
+        let solution = "5764801\n17807724\n".parse::<Solution>().unwrap();
+
+        assert_eq!(
+            solution.solve_typed(Part::One).unwrap().answer,
+            Answer::from(14897079u64)
+        );
+        assert_eq!(
+            solution.solve_typed(Part::Two),
+            Err(SolveError::NotImplemented(Part::Two))
+        );
+    }
+
+    #[test]
+    fn test_custom_handshake() {
+        let handshake = Handshake {
+            subject: 5,
+            modulus: 1_000_000_007,
+        };
+        let (card_loop_size, door_loop_size) = (123_456_789, 987_654);
+
+        let card_key = mod_pow(5, card_loop_size, handshake.modulus);
+        let door_key = mod_pow(5, door_loop_size, handshake.modulus);
+
+        assert_eq!(handshake.loop_size(card_key), Some(card_loop_size));
+        assert_eq!(
+            handshake.encryption_key(door_key, card_loop_size),
+            handshake.encryption_key(card_key, door_loop_size)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = "5764801\n".parse::<Solution>().unwrap_err();
+
+        assert_eq!(e.to_string(), "line 2: Missing door public key (in \"\")");
+
+        let e = "5764801\n20201227".parse::<Solution>().unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "line 2: Public key must be less than the modulus 20201227 (in \"20201227\")"
+        );
+    }
+}
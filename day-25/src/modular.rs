@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+/// `a * b mod modulus`, widened so it can't overflow
+fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (u128::from(a) * u128::from(b) % u128::from(modulus)) as u64
+}
+
+/// `base^exponent mod modulus` by repeated squaring
+pub(crate) fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut base = base % modulus;
+    let mut result = 1 % modulus;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// Inverse of `value` modulo `modulus`, if they're coprime
+fn mod_inverse(value: u64, modulus: u64) -> Option<u64> {
+    let (mut old_r, mut r) = (i128::from(value % modulus), i128::from(modulus));
+    let (mut old_x, mut x) = (1i128, 0i128);
+
+    while r != 0 {
+        let q = old_r / r;
+
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+    }
+
+    (old_r == 1).then(|| old_x.rem_euclid(i128::from(modulus)) as u64)
+}
+
+/// Smallest `x` such that `base^x ≡ target (mod modulus)`, found with baby-step giant-step
+/// in O(sqrt(modulus)) time and memory. `base` must be coprime with `modulus`
+pub(crate) fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    let (base, target) = (base % modulus, target % modulus);
+    let mut step = (modulus as f64).sqrt() as u64;
+    // float square root may be off for large moduli
+    while u128::from(step) * u128::from(step) < u128::from(modulus) {
+        step += 1;
+    }
+
+    // baby steps: base^j for j < step, keeping the smallest j for every value
+    let mut baby_steps = HashMap::with_capacity(step as usize);
+    let mut power = 1 % modulus;
+    for j in 0..step {
+        baby_steps.entry(power).or_insert(j);
+        power = mul_mod(power, base, modulus);
+    }
+
+    // giant steps: target * base^(-step * i) for i < step
+    let giant_factor = mod_inverse(mod_pow(base, step, modulus), modulus)?;
+    let mut value = target;
+    for i in 0..step {
+        if let Some(&j) = baby_steps.get(&value) {
+            return Some(i * step + j);
+        }
+        value = mul_mod(value, giant_factor, modulus);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(7, 8, 20201227), 5764801);
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(5, 0, 13), 1);
+        assert_eq!(mod_pow(5, 3, 1), 0);
+        assert_eq!(mod_pow(u64::MAX - 1, 2, u64::MAX), 1);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(10, 7), Some(5));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        assert_eq!(discrete_log(7, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_log(7, 17807724, 20201227), Some(11));
+        assert_eq!(discrete_log(3, 1, 7), Some(0));
+        // composite modulus, 3 has order 4 modulo 10
+        assert_eq!(discrete_log(3, 7, 10), Some(3));
+        // powers of 2 modulo 7 are 1, 2 and 4 only
+        assert_eq!(discrete_log(2, 3, 7), None);
+        assert_eq!(discrete_log(2, 4, 8), None);
+    }
+
+    #[test]
+    fn test_discrete_log_roundtrip() {
+        let modulus = 1_000_000_007;
+
+        for exponent in [1, 12345, 999_999, 500_000_003] {
+            let target = mod_pow(5, exponent, modulus);
+
+            assert_eq!(discrete_log(5, target, modulus), Some(exponent));
+        }
+    }
+}
@@ -1,24 +1,22 @@
-use std::error::Error;
-
 use advent_utils::Part;
+use common::{SolveError, Solved};
+use registry::Day;
 use yew::{
     macros::html, ChangeData, Component, ComponentLink, Html, InputData, MouseEvent, ShouldRender,
 };
 
-use crate::days::Day;
-
 #[derive(Debug)]
 pub(crate) struct App {
     link: ComponentLink<Self>,
     current_part: Part,
-    day: Day,
+    day: &'static Day,
     input_data: String,
-    solution: Option<Result<String, Box<dyn Error>>>,
+    solution: Option<Result<Solved, SolveError>>,
 }
 
 #[derive(Debug)]
 pub(crate) enum Message {
-    ChooseDay(Day),
+    ChooseDay(&'static Day),
     ChoosePart(Part),
     UpdateInputData(String),
     Evaluate,
@@ -32,7 +30,7 @@ impl Component for App {
         Self {
             link,
             current_part: Part::One,
-            day: Day::Day01,
+            day: &registry::days()[0],
             solution: None,
             input_data: String::new(),
         }
@@ -92,18 +90,30 @@ impl Component for App {
 
         let solution = match &self.solution {
             Some(result) => match result {
-                Ok(answer) => html! {
+                Ok(solved) => html! {
                     <p>
                         { "Here is your answer: " }
-                        <pre><code> { answer }</code></pre>
+                        <pre><code> { &solved.answer }</code></pre>
+                        <pre><code> { &solved.message }</code></pre>
                     </p>
                 },
-                Err(e) => html! {
+                Err(SolveError::Parse(e)) => html! {
                     <p>
-                        { "There was an error computing your answer: " }
+                        { "Your input couldn't be parsed: " }
                         <pre><code> { e }</code></pre>
                     </p>
                 },
+                Err(SolveError::NoSolution(reason)) => html! {
+                    <p>
+                        { "There is no solution for your input: " }
+                        <pre><code> { reason }</code></pre>
+                    </p>
+                },
+                Err(SolveError::NotImplemented(part)) => html! {
+                    <p>
+                        { format!("Part {:?} of {} is not implemented yet", part, self.day) }
+                    </p>
+                },
             },
             None => html! {
                 <p>
@@ -130,15 +140,15 @@ impl Component for App {
                     <select
                         onchange=self.link.callback(|e: ChangeData| {
                             if let ChangeData::Select(e) = e {
-                                Message::ChooseDay(Day::DAYS[e.selected_index() as usize])
+                                Message::ChooseDay(&registry::days()[e.selected_index() as usize])
                             } else {
                                 unreachable!()
                             }
                         })
-                        value={(self.day.day_number() - 1).to_string()}
+                        value={self.day.number().to_string()}
                     >
-                        { for Day::DAYS.iter().map(|day| html! {
-                            <option value={(*day as usize).to_string()}>{ day }</option>
+                        { for registry::days().iter().map(|day| html! {
+                            <option value={day.number().to_string()}>{ day }</option>
                         })}
                     </select>
                     { for parts }
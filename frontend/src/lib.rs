@@ -4,7 +4,6 @@ use cfg_if::cfg_if;
 use wasm_bindgen::prelude::wasm_bindgen;
 
 mod app;
-mod days;
 
 cfg_if! {
     // When the `console_error_panic_hook` feature is enabled, we can call the
@@ -0,0 +1,58 @@
+use std::{error::Error, time::Duration};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Minimal HTTP backend used for downloading inputs.
+///
+/// Non-success statuses are not errors here, they're reported in [`Response::status`].
+pub trait HttpClient: Send + Sync {
+    fn get(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<Response, Box<dyn Error + Send + Sync>>;
+}
+
+/// Default backend, blocking [`ureq`] agent with rustls
+#[derive(Debug)]
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().timeout(TIMEOUT).build(),
+        }
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<Response, Box<dyn Error + Send + Sync>> {
+        let request = headers
+            .iter()
+            .fold(self.agent.get(url), |request, (name, value)| {
+                request.set(name, value)
+            });
+
+        let response = match request.call() {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Response {
+            status: response.status(),
+            body: response.into_string()?,
+        })
+    }
+}
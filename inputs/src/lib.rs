@@ -0,0 +1,501 @@
+//! Local cache of puzzle inputs, downloading missing ones from Advent of Code
+//!
+//! Inputs are stored as `<cache dir>/<user>/day-NN.txt` and are never downloaded twice.
+
+use std::{
+    env::var,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Duration,
+};
+
+use displaydoc::Display;
+use thiserror::Error;
+
+mod http;
+mod throttle;
+
+pub use http::{HttpClient, Response, UreqClient};
+use throttle::Throttle;
+
+const YEAR: u32 = 2020;
+
+pub const DEFAULT_ENDPOINT: &str = "https://adventofcode.com";
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Identifies the tool to the server; contact details can be added with `AOC_USER_AGENT`
+pub const DEFAULT_USER_AGENT: &str = "github.com/utter-step/advent-2020";
+
+/// File remembering when the last request was made, shared by all users of a cache
+const STAMP_FILE: &str = "last-request";
+
+#[derive(Debug, Display, Error)]
+pub enum FetchError {
+    /// invalid user name {0:?}, only ASCII letters, digits, '-', '_' and '.' are allowed
+    InvalidUser(String),
+    /// failed to access {path}: {source}
+    Io { path: String, source: io::Error },
+    /// request to {url} failed: {source}
+    Request {
+        url: String,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// {url} responded with status {status}: {body}
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+    /// {url} responded with an empty input
+    EmptyInput { url: String },
+}
+
+impl FetchError {
+    fn io(path: &Path, source: io::Error) -> Self {
+        Self::Io {
+            path: path.display().to_string(),
+            source,
+        }
+    }
+}
+
+pub struct Fetcher {
+    session: String,
+    user: Option<String>,
+    endpoint: String,
+    user_agent: String,
+    cache_dir: PathBuf,
+    throttle: Throttle,
+    client: Box<dyn HttpClient>,
+    // held while checking the cache and downloading, so a day is never requested twice
+    lock: Mutex<()>,
+}
+
+impl fmt::Debug for Fetcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // session token is a secret, keep it out of logs
+        f.debug_struct("Fetcher")
+            .field("user", &self.user)
+            .field("endpoint", &self.endpoint)
+            .field("user_agent", &self.user_agent)
+            .field("cache_dir", &self.cache_dir)
+            .field("throttle", &self.throttle)
+            .finish_non_exhaustive()
+    }
+}
+
+/// 64-bit FNV-1a, stable between builds unlike `DefaultHasher`
+fn fnv1a(data: &str) -> u64 {
+    data.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+impl Fetcher {
+    pub fn new(session: impl Into<String>, cache_dir: impl Into<PathBuf>) -> Self {
+        let cache_dir = cache_dir.into();
+
+        Self {
+            session: session.into(),
+            user: None,
+            endpoint: DEFAULT_ENDPOINT.to_owned(),
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            throttle: Throttle::new(DEFAULT_MIN_INTERVAL, cache_dir.join(STAMP_FILE)),
+            cache_dir,
+            client: Box::<UreqClient>::default(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Configures fetcher from the environment, `None` if `AOC_SESSION` is not set.
+    ///
+    /// `AOC_USER`, `AOC_ENDPOINT`, `AOC_USER_AGENT` and `AOC_CACHE_DIR` override the defaults;
+    /// the cache is kept in `$XDG_CACHE_HOME/advent-2020` (or `~/.cache/advent-2020`) otherwise.
+    pub fn from_env() -> Option<Self> {
+        let session = var("AOC_SESSION")
+            .ok()
+            .filter(|session| !session.is_empty())?;
+
+        let cache_dir = var("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .or_else(|_| var("XDG_CACHE_HOME").map(|dir| Path::new(&dir).join("advent-2020")))
+            .or_else(|_| var("HOME").map(|dir| Path::new(&dir).join(".cache/advent-2020")))
+            .unwrap_or_else(|_| PathBuf::from(".cache/advent-2020"));
+
+        let mut fetcher = Self::new(session, cache_dir);
+        if let Ok(user) = var("AOC_USER") {
+            fetcher = fetcher.with_user(user);
+        }
+        if let Ok(endpoint) = var("AOC_ENDPOINT") {
+            fetcher = fetcher.with_endpoint(endpoint);
+        }
+        if let Ok(user_agent) = var("AOC_USER_AGENT") {
+            fetcher = fetcher.with_user_agent(user_agent);
+        }
+
+        Some(fetcher)
+    }
+
+    /// Name of the cache subdirectory, a hash of the session token by default
+    pub fn with_user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Base URL, `https://adventofcode.com` by default
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    /// `User-Agent` header, the repository URL by default
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    pub fn with_min_interval(mut self, interval: Duration) -> Self {
+        self.throttle = Throttle::new(interval, self.cache_dir.join(STAMP_FILE));
+        self
+    }
+
+    pub fn with_client(mut self, client: impl HttpClient + 'static) -> Self {
+        self.client = Box::new(client);
+        self
+    }
+
+    fn user_dir(&self) -> Result<String, FetchError> {
+        let user = match &self.user {
+            Some(user) => user.clone(),
+            None => return Ok(format!("session-{:016x}", fnv1a(&self.session))),
+        };
+
+        let is_valid = !user.is_empty()
+            && !user.starts_with('.')
+            && user
+                .chars()
+                .all(|chr| chr.is_ascii_alphanumeric() || "-_.".contains(chr));
+
+        if is_valid {
+            Ok(user)
+        } else {
+            Err(FetchError::InvalidUser(user))
+        }
+    }
+
+    /// Where the input for `day` is (or will be) cached
+    pub fn cache_path(&self, day: u32) -> Result<PathBuf, FetchError> {
+        Ok(self
+            .cache_dir
+            .join(self.user_dir()?)
+            .join(format!("day-{:02}.txt", day)))
+    }
+
+    fn url(&self, day: u32) -> String {
+        format!(
+            "{}/{}/day/{}/input",
+            self.endpoint.trim_end_matches('/'),
+            YEAR,
+            day
+        )
+    }
+
+    /// Input for `day`, downloaded only if it's not cached yet
+    pub fn input(&self, day: u32) -> Result<String, FetchError> {
+        let path = self.cache_path(day)?;
+
+        let _guard = self
+            .lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        match fs::read_to_string(&path) {
+            Ok(input_data) => return Ok(input_data),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(FetchError::io(&path, e)),
+        }
+
+        let url = self.url(day);
+        let cookie = format!("session={}", self.session);
+        let headers = [
+            ("Cookie", cookie.as_str()),
+            ("User-Agent", self.user_agent.as_str()),
+        ];
+
+        self.throttle.wait();
+        let response = self.client.get(&url, &headers);
+        self.throttle
+            .record()
+            .map_err(|e| FetchError::io(&self.cache_dir.join(STAMP_FILE), e))?;
+
+        let Response { status, body } = response.map_err(|source| FetchError::Request {
+            url: url.clone(),
+            source,
+        })?;
+
+        if status != 200 {
+            // explanation is usually a single line, don't flood the output with HTML pages
+            let body = body.trim().lines().next().unwrap_or_default().to_owned();
+
+            return Err(FetchError::Status { url, status, body });
+        }
+        if body.is_empty() {
+            return Err(FetchError::EmptyInput { url });
+        }
+
+        store(&path, &body)?;
+
+        Ok(body)
+    }
+}
+
+/// Writes through a temporary file, so an interrupted run can't leave a truncated input behind
+fn store(path: &Path, contents: &str) -> Result<(), FetchError> {
+    let partial = path.with_extension("partial");
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| FetchError::io(parent, e))?;
+    }
+
+    fs::write(&partial, contents).map_err(|e| FetchError::io(&partial, e))?;
+    fs::rename(&partial, path).map_err(|e| FetchError::io(path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        process,
+        sync::Arc,
+        thread,
+        time::SystemTime,
+    };
+
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("inputs-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        dir
+    }
+
+    /// Serves `/2020/day/1/input` for `session=secret` and 404 for anything else,
+    /// returns its base URL and a log of requested paths
+    fn stub_server() -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        let requests = Arc::clone(&log);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let head = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect::<Vec<_>>();
+
+                let path = head[0].split(' ').nth(1).unwrap().to_owned();
+                let authorized = head.iter().any(|line| line == "Cookie: session=secret");
+                let has_agent = head
+                    .iter()
+                    .any(|line| line.starts_with("User-Agent: github.com/"));
+
+                let (status, body) = match path.as_str() {
+                    "/2020/day/1/input" if authorized && has_agent => ("200 OK", "1721\n979\n"),
+                    "/2020/day/1/input" => ("400 Bad Request", "Puzzle inputs differ by user."),
+                    _ => ("404 Not Found", "Not unlocked yet"),
+                };
+
+                requests.lock().unwrap().push(path);
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (endpoint, log)
+    }
+
+    #[test]
+    fn test_stub_server() {
+        let cache_dir = temp_dir("stub");
+        let (endpoint, log) = stub_server();
+
+        let fetcher = Fetcher::new("secret", &cache_dir)
+            .with_endpoint(format!("{}/", endpoint))
+            .with_user("alice")
+            .with_min_interval(Duration::ZERO);
+
+        assert_eq!(fetcher.input(1).unwrap(), "1721\n979\n");
+        assert_eq!(fetcher.input(1).unwrap(), "1721\n979\n");
+        assert_eq!(
+            fs::read_to_string(cache_dir.join("alice/day-01.txt")).unwrap(),
+            "1721\n979\n"
+        );
+
+        let e = fetcher.input(2).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            format!(
+                "{}/2020/day/2/input responded with status 404: Not unlocked yet",
+                endpoint
+            )
+        );
+        assert!(!cache_dir.join("alice/day-02.txt").exists());
+
+        let e = Fetcher::new("stolen", &cache_dir)
+            .with_endpoint(&endpoint)
+            .with_min_interval(Duration::ZERO)
+            .input(1)
+            .unwrap_err();
+        assert!(matches!(e, FetchError::Status { status: 400, .. }));
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "/2020/day/1/input",
+                "/2020/day/2/input",
+                "/2020/day/1/input"
+            ]
+        );
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    /// Answers with the requested URL, remembering when every request was made
+    struct RecordingClient(Arc<Mutex<Vec<SystemTime>>>);
+
+    impl HttpClient for RecordingClient {
+        fn get(
+            &self,
+            url: &str,
+            _headers: &[(&str, &str)],
+        ) -> Result<Response, Box<dyn std::error::Error + Send + Sync>> {
+            self.0.lock().unwrap().push(SystemTime::now());
+
+            Ok(Response {
+                status: 200,
+                body: url.to_owned(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let cache_dir = temp_dir("rate-limit");
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let interval = Duration::from_millis(50);
+
+        let fetcher = || {
+            Fetcher::new("token", &cache_dir)
+                .with_endpoint("stub:")
+                .with_min_interval(interval)
+                .with_client(RecordingClient(Arc::clone(&requests)))
+        };
+
+        let first = fetcher();
+        assert_eq!(first.input(1).unwrap(), "stub:/2020/day/1/input");
+        assert_eq!(first.input(2).unwrap(), "stub:/2020/day/2/input");
+        // a separate fetcher, as in the next run, is limited by the same stamp
+        assert_eq!(fetcher().input(3).unwrap(), "stub:/2020/day/3/input");
+
+        // cached inputs are not requested again
+        for day in 1..=3 {
+            fetcher().input(day).unwrap();
+        }
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        // measured with the same clock as the stamp, so the check doesn't depend on timing
+        assert!(requests
+            .windows(2)
+            .all(|pair| pair[1].duration_since(pair[0]).unwrap() >= interval));
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    struct HeadersClient(Arc<Mutex<Vec<String>>>);
+
+    impl HttpClient for HeadersClient {
+        fn get(
+            &self,
+            _url: &str,
+            headers: &[(&str, &str)],
+        ) -> Result<Response, Box<dyn std::error::Error + Send + Sync>> {
+            let mut sent = self.0.lock().unwrap();
+            sent.extend(
+                headers
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value)),
+            );
+
+            Ok(Response {
+                status: 200,
+                body: "1\n".to_owned(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_headers() {
+        let cache_dir = temp_dir("headers");
+        let headers = Arc::new(Mutex::new(Vec::new()));
+
+        Fetcher::new("token", &cache_dir)
+            .with_min_interval(Duration::ZERO)
+            .with_client(HeadersClient(Arc::clone(&headers)))
+            .input(1)
+            .unwrap();
+        Fetcher::new("token", &cache_dir)
+            .with_min_interval(Duration::ZERO)
+            .with_user_agent("advent-2020 fork by someone@example.com")
+            .with_client(HeadersClient(Arc::clone(&headers)))
+            .input(2)
+            .unwrap();
+
+        assert_eq!(
+            *headers.lock().unwrap(),
+            [
+                "Cookie: session=token",
+                "User-Agent: github.com/utter-step/advent-2020",
+                "Cookie: session=token",
+                "User-Agent: advent-2020 fork by someone@example.com",
+            ]
+        );
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_cache_path() {
+        let fetcher = Fetcher::new("token", "cache");
+
+        assert_eq!(
+            fetcher.cache_path(7).unwrap(),
+            Path::new("cache/session-26c4b17d50b3c152/day-07.txt")
+        );
+        assert_eq!(
+            fetcher.with_user("bob").cache_path(25).unwrap(),
+            Path::new("cache/bob/day-25.txt")
+        );
+
+        let e = Fetcher::new("token", "cache")
+            .with_user("../bob")
+            .cache_path(1)
+            .unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid user name \"../bob\", only ASCII letters, digits, '-', '_' and '.' are allowed"
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use std::{
+    fs, io,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Keeps requests at least `interval` apart, remembering the last one in a file,
+/// so that consecutive runs are limited as well
+#[derive(Debug)]
+pub(crate) struct Throttle {
+    interval: Duration,
+    stamp: PathBuf,
+}
+
+impl Throttle {
+    pub(crate) fn new(interval: Duration, stamp: PathBuf) -> Self {
+        Self { interval, stamp }
+    }
+
+    fn last_request(&self) -> Option<SystemTime> {
+        let nanos = fs::read_to_string(&self.stamp).ok()?.trim().parse().ok()?;
+
+        UNIX_EPOCH.checked_add(Duration::from_nanos(nanos))
+    }
+
+    /// Sleeps until the next request is allowed
+    pub(crate) fn wait(&self) {
+        let Some(last_request) = self.last_request() else {
+            return;
+        };
+
+        // a stamp from the future (e.g. after a clock change) shouldn't block forever
+        let elapsed = SystemTime::now()
+            .duration_since(last_request)
+            .unwrap_or_default();
+
+        if let Some(remaining) = self.interval.checked_sub(elapsed) {
+            thread::sleep(remaining);
+        }
+    }
+
+    pub(crate) fn record(&self) -> io::Result<()> {
+        // full precision, a truncated stamp would let the next request come a bit too early
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+
+        if let Some(parent) = self.stamp.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&self.stamp, nanos.to_string())
+    }
+}
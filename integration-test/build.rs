@@ -0,0 +1,40 @@
+use std::{env, fs, path::Path};
+
+/// Generates a test per day listed in `reference.toml`, ignored if the day has a `skip` reason
+fn main() {
+    println!("cargo:rerun-if-changed=reference.toml");
+
+    let reference: toml::Table = fs::read_to_string("reference.toml")
+        .expect("failed to read reference.toml")
+        .parse()
+        .expect("failed to parse reference.toml");
+
+    let tests = reference
+        .iter()
+        .map(|(key, entry)| {
+            let number: u32 = key
+                .strip_prefix("day-")
+                .and_then(|number| number.parse().ok())
+                .unwrap_or_else(|| panic!("invalid day key in reference.toml: {}", key));
+
+            let ignore = match entry.get("skip") {
+                Some(reason) => {
+                    let reason = reason
+                        .as_str()
+                        .unwrap_or_else(|| panic!("skip reason of {} is not a string", key));
+
+                    format!("#[ignore = {:?}]\n", reason)
+                }
+                None => String::new(),
+            };
+
+            format!(
+                "#[test]\n{}fn day_{:02}() {{\n    check_day({});\n}}\n\n",
+                ignore, number, number
+            )
+        })
+        .collect::<String>();
+
+    let out_file = Path::new(&env::var("OUT_DIR").unwrap()).join("day_tests.rs");
+    fs::write(out_file, tests).expect("failed to write generated tests");
+}
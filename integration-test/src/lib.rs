@@ -1,33 +1,238 @@
+//! Golden (raw) answers for every registered day, stored in `reference.toml`.
+//!
+//! Run `BLESS=1 cargo test -p integration-test` to record current answers as the expected ones.
+//! Days without an `input.txt` have to be listed with a `skip` reason instead of answers,
+//! their tests are ignored.
+
 #[cfg(test)]
 mod tests {
-    use pretty_assertions::assert_eq;
-    use std::process::Command;
+    use std::{
+        collections::BTreeMap,
+        env, fs,
+        path::{Path, PathBuf},
+        sync::Mutex,
+    };
+
+    use advent_utils::Part;
+    use common::Answer;
+    use registry::Day;
+
+    const REFERENCE_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/reference.toml");
+
+    /// Serializes reference file rewrites between concurrently running tests
+    static REFERENCE_LOCK: Mutex<()> = Mutex::new(());
+
+    type Answers = BTreeMap<&'static str, Answer>;
+
+    fn part_name(part: Part) -> &'static str {
+        match part {
+            Part::One => "one",
+            Part::Two => "two",
+        }
+    }
+
+    fn day_key(number: u32) -> String {
+        format!("day-{:02}", number)
+    }
+
+    fn is_blessing() -> bool {
+        env::var_os("BLESS").is_some()
+    }
+
+    fn read_reference() -> toml::Table {
+        fs::read_to_string(REFERENCE_FILE)
+            .expect("failed to read reference file")
+            .parse()
+            .expect("failed to parse reference file")
+    }
+
+    fn input_file(day: &Day) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(day_key(day.number()))
+            .join("input.txt")
+    }
+
+    fn solve_day(day: &Day) -> Answers {
+        let input_data = fs::read_to_string(input_file(day)).expect("failed to read input");
+        let solver = day
+            .parse(&input_data)
+            .unwrap_or_else(|e| panic!("day {:02}: failed to parse input: {}", day.number(), e));
+
+        day.implemented_parts()
+            .into_iter()
+            .map(|part| {
+                let solved = solver.solve(part).unwrap_or_else(|e| {
+                    panic!(
+                        "day {:02}: failed to solve part {:?}: {}",
+                        day.number(),
+                        part,
+                        e
+                    )
+                });
+
+                (part_name(part), solved.answer)
+            })
+            .collect()
+    }
+
+    fn to_toml(answer: &Answer) -> toml::Value {
+        match answer {
+            &Answer::Integer(value) => match i64::try_from(value) {
+                Ok(value) => value.into(),
+                Err(_) => value.to_string().into(),
+            },
+            answer => answer.to_string().into(),
+        }
+    }
+
+    fn expected_answers(number: u32) -> BTreeMap<&'static str, String> {
+        let reference = read_reference();
+
+        let table = match reference.get(&day_key(number)) {
+            Some(toml::Value::Table(table)) => table,
+            Some(_) => panic!("day {:02}: reference entry is not a table", number),
+            None => return BTreeMap::new(),
+        };
+
+        [Part::One, Part::Two]
+            .iter()
+            .filter_map(|&part| {
+                let answer = match table.get(part_name(part))? {
+                    toml::Value::String(answer) => answer.clone(),
+                    other => other.to_string(),
+                };
+
+                Some((part_name(part), answer))
+            })
+            .collect()
+    }
+
+    fn bless(number: u32, answers: &Answers) {
+        let _guard = REFERENCE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut reference = read_reference();
+        reference.insert(
+            day_key(number),
+            toml::Value::Table(
+                answers
+                    .iter()
+                    .map(|(&part, answer)| (part.to_owned(), to_toml(answer)))
+                    .collect(),
+            ),
+        );
+
+        fs::write(REFERENCE_FILE, toml::to_string(&reference).unwrap())
+            .expect("failed to write reference file");
+    }
+
+    fn check_day(number: u32) {
+        let day = registry::find(number).unwrap_or_else(|| {
+            panic!(
+                "day {:02} has reference answers, but isn't registered",
+                number
+            )
+        });
+
+        let actual = solve_day(day);
+
+        if is_blessing() {
+            bless(number, &actual);
+
+            return;
+        }
+
+        let expected = expected_answers(number);
+        let actual = actual
+            .iter()
+            .map(|(&part, answer)| (part, answer.to_string()))
+            .collect::<BTreeMap<_, _>>();
+
+        let regressions = [Part::One, Part::Two]
+            .iter()
+            .map(|&part| part_name(part))
+            .filter_map(|part| match (expected.get(part), actual.get(part)) {
+                (Some(expected), Some(actual)) if expected == actual => None,
+                (Some(expected), Some(actual)) => Some(format!(
+                    "  part {}: expected {:?}, got {:?}",
+                    part, expected, actual
+                )),
+                (Some(expected), None) => Some(format!(
+                    "  part {}: expected {:?}, but part is not implemented",
+                    part, expected
+                )),
+                (None, Some(actual)) => Some(format!(
+                    "  part {}: got {:?}, but there is no reference answer (run with BLESS=1 to record it)",
+                    part, actual
+                )),
+                (None, None) => None,
+            })
+            .collect::<Vec<_>>();
+
+        if !regressions.is_empty() {
+            panic!("day {:02} regressed:\n{}", number, regressions.join("\n"));
+        }
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/day_tests.rs"));
+
+    fn is_skipped(reference: &toml::Table, day: &Day) -> bool {
+        reference
+            .get(&day_key(day.number()))
+            .and_then(|entry| entry.get("skip"))
+            .is_some()
+    }
+
+    fn list(days: &[&Day]) -> String {
+        days.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    #[test]
+    fn test_reference_covers_all_days() {
+        let reference = read_reference();
+
+        let (missing, without_input): (Vec<_>, Vec<_>) = registry::days()
+            .iter()
+            .filter(|day| !reference.contains_key(&day_key(day.number())))
+            .partition(|day| input_file(day).exists());
+
+        assert!(
+            without_input.is_empty(),
+            "no input.txt for {}: add one, or a `skip = \"<reason>\"` entry to reference.toml",
+            list(&without_input),
+        );
+
+        if is_blessing() {
+            for day in missing {
+                bless(day.number(), &solve_day(day));
+            }
+
+            return;
+        }
+
+        assert!(
+            missing.is_empty(),
+            "no reference answers for {} (run with BLESS=1 to record them)",
+            list(&missing),
+        );
+    }
 
     #[test]
-    fn test_expected_output() {
-        Command::new("cargo")
-            .current_dir("../")
-            .arg("build")
-            .arg("--release")
-            .arg("-p")
-            .arg("run-all")
-            .status()
-            .expect("failed to build");
-
-        // assert that all existing solutions are valid after any change
-        let output = Command::new("./target/release/run-all")
-            .current_dir("../")
-            .output()
-            .expect("failed to run all solutions");
-
-        assert_eq!(
-            String::from_utf8(output.stdout)
-                .unwrap()
-                .split('\n')
-                .collect::<Vec<_>>(),
-            include_str!("../reference.txt")
-                .split('\n')
-                .collect::<Vec<_>>(),
+    fn test_skipped_days_have_no_input() {
+        let reference = read_reference();
+
+        let skipped_with_input = registry::days()
+            .iter()
+            .filter(|day| is_skipped(&reference, day) && input_file(day).exists())
+            .collect::<Vec<_>>();
+
+        assert!(
+            skipped_with_input.is_empty(),
+            "{} skipped, but input.txt is there: remove the `skip` entry and run with BLESS=1",
+            list(&skipped_with_input),
         );
     }
 }
@@ -0,0 +1,118 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::Parser;
+use displaydoc::Display;
+use thiserror::Error;
+
+mod template;
+mod wiring;
+
+use wiring::WiringError;
+
+/// Generate a new day crate and register it in the workspace, `registry` and its day table
+#[derive(Debug, Parser)]
+#[command(name = "new-day")]
+struct Args {
+    /// Day number
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// Workspace root, the one this tool is built from by default
+    #[arg(long, value_name = "PATH")]
+    root: Option<PathBuf>,
+}
+
+#[derive(Debug, Display, Error)]
+enum ScaffoldError {
+    /// {path} already exists
+    CrateExists { path: String },
+    /// failed to update {path}: {source}
+    Wiring {
+        path: &'static str,
+        source: WiringError,
+    },
+    /// failed to access {path}: {source}
+    Io { path: String, source: io::Error },
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    let io_error = |source| ScaffoldError::Io {
+        path: path.display().to_string(),
+        source,
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+
+    fs::write(path, contents).map_err(io_error)
+}
+
+/// Prepares every change before touching the disk, so a failure can't leave the day half-registered
+fn scaffold(root: &Path, day: u32) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let crate_dir = root.join(template::package_name(day));
+    if crate_dir.exists() {
+        return Err(ScaffoldError::CrateExists {
+            path: crate_dir.display().to_string(),
+        });
+    }
+
+    let mut changes = template::files(day)
+        .into_iter()
+        .map(|(path, contents)| (crate_dir.join(path), contents))
+        .collect::<Vec<_>>();
+
+    for list in wiring::ALL {
+        let path = root.join(list.path);
+        let contents = list
+            .insert(&read(&path)?, day)
+            .map_err(|source| ScaffoldError::Wiring {
+                path: list.path,
+                source,
+            })?;
+
+        changes.push((path, contents));
+    }
+
+    for (path, contents) in &changes {
+        write(path, contents)?;
+    }
+
+    Ok(changes.into_iter().map(|(path, _)| path).collect())
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let root = args.root.unwrap_or_else(|| {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("new-day should be a workspace member")
+            .to_owned()
+    });
+
+    match scaffold(&root, args.day) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+
+            ExitCode::FAILURE
+        }
+    }
+}
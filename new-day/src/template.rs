@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+
+const MANIFEST: &str = include_str!("../templates/Cargo.toml.template");
+const MAIN: &str = include_str!("../templates/main.rs.template");
+const LIB: &str = include_str!("../templates/lib.rs.template");
+
+/// Package name of a day crate, e.g. `day-07`
+pub(crate) fn package_name(day: u32) -> String {
+    format!("day-{:02}", day)
+}
+
+/// Name of a day crate as used in Rust code, e.g. `day_07`
+pub(crate) fn crate_name(day: u32) -> String {
+    format!("day_{:02}", day)
+}
+
+fn render(template: &str, day: u32) -> String {
+    template
+        .replace("{package}", &package_name(day))
+        .replace("{crate}", &crate_name(day))
+        .replace("{number}", &day.to_string())
+}
+
+/// Files of a freshly generated day crate, relative to the crate directory
+pub(crate) fn files(day: u32) -> Vec<(PathBuf, String)> {
+    [
+        ("Cargo.toml", MANIFEST),
+        ("src/main.rs", MAIN),
+        ("src/lib.rs", LIB),
+    ]
+    .into_iter()
+    .map(|(path, template)| (PathBuf::from(path), render(template, day)))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_files() {
+        let files = files(7);
+
+        assert_eq!(files.len(), 3);
+        assert!(files.iter().all(|(_, contents)| {
+            ["{package}", "{crate}", "{number}"]
+                .iter()
+                .all(|placeholder| !contents.contains(placeholder))
+        }));
+
+        let (_, manifest) = &files[0];
+        assert!(manifest.contains("name = \"day-07\"\n"));
+
+        let (_, main) = &files[1];
+        assert!(main.contains("use day_07::Solution;\n"));
+
+        let (_, lib) = &files[2];
+        assert!(lib.contains("fn day_number() -> u32 {\n        7\n    }"));
+    }
+}
@@ -0,0 +1,224 @@
+use displaydoc::Display;
+use thiserror::Error;
+
+use crate::template::{crate_name, package_name};
+
+#[derive(Debug, Display, Error, PartialEq)]
+pub(crate) enum WiringError {
+    /// no day entries found to insert day {0} next to
+    NoEntries(u32),
+    /// day {0} is already listed
+    AlreadyWired(u32),
+}
+
+/// A file listing every day crate, one entry per line, ordered by day number
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct DayList {
+    /// Path relative to the workspace root
+    pub path: &'static str,
+    /// Day number of an entry line, `None` for any other line
+    day_of: fn(&str) -> Option<u32>,
+    entry: fn(u32) -> String,
+}
+
+pub(crate) const WORKSPACE_MEMBERS: DayList = DayList {
+    path: "Cargo.toml",
+    day_of: |line| {
+        line.trim()
+            .strip_prefix("\"day-")?
+            .strip_suffix("\",")?
+            .parse()
+            .ok()
+    },
+    entry: |day| format!("    \"{}\",", package_name(day)),
+};
+
+pub(crate) const REGISTRY_DEPENDENCIES: DayList = DayList {
+    path: "registry/Cargo.toml",
+    day_of: |line| {
+        let (name, dependency) = line.strip_prefix("day-")?.split_once(" = ")?;
+
+        dependency.starts_with('{').then(|| name.parse().ok())?
+    },
+    entry: |day| format!("{0} = {{ path = \"../{0}\" }}", package_name(day)),
+};
+
+pub(crate) const REGISTRY_TABLE: DayList = DayList {
+    path: "registry/src/lib.rs",
+    day_of: |line| {
+        line.trim()
+            .strip_prefix("Day::new::<day_")?
+            .strip_suffix("::Solution>(),")?
+            .parse()
+            .ok()
+    },
+    entry: |day| format!("        Day::new::<{}::Solution>(),", crate_name(day)),
+};
+
+pub(crate) const ALL: [DayList; 3] = [WORKSPACE_MEMBERS, REGISTRY_DEPENDENCIES, REGISTRY_TABLE];
+
+impl DayList {
+    /// Inserts an entry for `day` between its neighbours, keeping the rest of the file intact
+    pub(crate) fn insert(&self, text: &str, day: u32) -> Result<String, WiringError> {
+        let lines = text.split_inclusive('\n').collect::<Vec<_>>();
+        let entries = lines
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, line)| Some((idx, (self.day_of)(line.trim_end())?)))
+            .collect::<Vec<_>>();
+
+        if entries.iter().any(|&(_, listed)| listed == day) {
+            return Err(WiringError::AlreadyWired(day));
+        }
+
+        // right after the last preceding day, or right before the first day if there's none
+        let position = match entries.iter().rev().find(|&&(_, listed)| listed < day) {
+            Some(&(idx, _)) => idx + 1,
+            None => entries.first().ok_or(WiringError::NoEntries(day))?.0,
+        };
+
+        let entry = format!("{}\n", (self.entry)(day));
+        let mut result = String::with_capacity(text.len() + entry.len());
+        for (idx, line) in lines.iter().enumerate() {
+            if idx == position {
+                result.push_str(&entry);
+            }
+            result.push_str(line);
+        }
+        if position == lines.len() {
+            result.push_str(&entry);
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    const WORKSPACE: &str = indoc!(
+        r#"
+        [workspace]
+
+        members = [
+            "day-01",
+            "day-03",
+
+            "common",
+        ]
+        "#
+    );
+
+    #[test]
+    fn test_insert_between() {
+        assert_eq!(
+            WORKSPACE_MEMBERS.insert(WORKSPACE, 2).unwrap(),
+            indoc!(
+                r#"
+                [workspace]
+
+                members = [
+                    "day-01",
+                    "day-02",
+                    "day-03",
+
+                    "common",
+                ]
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn test_insert_at_ends() {
+        assert_eq!(
+            WORKSPACE_MEMBERS.insert(WORKSPACE, 4).unwrap(),
+            indoc!(
+                r#"
+                [workspace]
+
+                members = [
+                    "day-01",
+                    "day-03",
+                    "day-04",
+
+                    "common",
+                ]
+                "#
+            )
+        );
+
+        let table = indoc!(
+            "
+            vec![
+                    Day::new::<day_02::Solution>(),
+            ]"
+        );
+
+        assert_eq!(
+            REGISTRY_TABLE.insert(table, 1).unwrap(),
+            indoc!(
+                "
+                vec![
+                        Day::new::<day_01::Solution>(),
+                        Day::new::<day_02::Solution>(),
+                ]"
+            )
+        );
+    }
+
+    #[test]
+    fn test_registry_dependencies() {
+        let manifest = indoc!(
+            r#"
+            common = { path = "../common" }
+
+            day-01 = { path = "../day-01" }
+
+            [dev-dependencies]
+            "#
+        );
+
+        assert_eq!(
+            REGISTRY_DEPENDENCIES.insert(manifest, 12).unwrap(),
+            indoc!(
+                r#"
+                common = { path = "../common" }
+
+                day-01 = { path = "../day-01" }
+                day-12 = { path = "../day-12" }
+
+                [dev-dependencies]
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            WORKSPACE_MEMBERS.insert(WORKSPACE, 3),
+            Err(WiringError::AlreadyWired(3))
+        );
+        assert_eq!(
+            REGISTRY_TABLE.insert(WORKSPACE, 3),
+            Err(WiringError::NoEntries(3))
+        );
+    }
+
+    #[test]
+    fn test_current_workspace() {
+        let files = [
+            include_str!("../../Cargo.toml"),
+            include_str!("../../registry/Cargo.toml"),
+            include_str!("../../registry/src/lib.rs"),
+        ];
+
+        for (list, text) in ALL.iter().zip(files) {
+            assert_eq!(list.insert(text, 1), Err(WiringError::AlreadyWired(1)));
+        }
+    }
+}
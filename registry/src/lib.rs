@@ -0,0 +1,142 @@
+//! Table of all implemented days, shared by `run-all`, `frontend` and `integration-test`
+
+use std::{error::Error, fmt, str::FromStr, sync::LazyLock};
+
+use advent_utils::{Part, Solver};
+use common::{SolveError, Solved, TypedSolver};
+
+/// Type-erased [`TypedSolver`], so that all days can be stored in one table.
+///
+/// Solutions are immutable after parsing, so they can be shared between threads.
+pub trait DynSolver: Send + Sync {
+    fn solve(&self, part: Part) -> Result<Solved, SolveError>;
+}
+
+impl<S: TypedSolver + Send + Sync> DynSolver for S {
+    fn solve(&self, part: Part) -> Result<Solved, SolveError> {
+        self.solve_typed(part)
+    }
+}
+
+type ParseFn = fn(&str) -> Result<Box<dyn DynSolver>, SolveError>;
+
+#[derive(Debug)]
+pub struct Day {
+    number: u32,
+    implemented_parts: fn() -> Vec<Part>,
+    parse: ParseFn,
+}
+
+impl Day {
+    fn new<S>() -> Self
+    where
+        S: Solver + TypedSolver + FromStr<Err = Box<dyn Error>> + Send + Sync + 'static,
+    {
+        Self {
+            number: S::day_number(),
+            implemented_parts: S::implemented_parts,
+            parse: |input_data| {
+                Ok(Box::new(
+                    input_data.parse::<S>().map_err(SolveError::parse)?,
+                ))
+            },
+        }
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn implemented_parts(&self) -> Vec<Part> {
+        (self.implemented_parts)()
+    }
+
+    pub fn parse(&self, input_data: &str) -> Result<Box<dyn DynSolver>, SolveError> {
+        (self.parse)(input_data)
+    }
+
+    pub fn solve(&self, part: Part, input_data: &str) -> Result<Solved, SolveError> {
+        if !self.implemented_parts().contains(&part) {
+            return Err(SolveError::NotImplemented(part));
+        }
+
+        self.parse(input_data)?.solve(part)
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:02}", self.number)
+    }
+}
+
+static DAYS: LazyLock<Vec<Day>> = LazyLock::new(|| {
+    vec![
+        Day::new::<day_01::Solution>(),
+        Day::new::<day_02::Solution>(),
+        Day::new::<day_03::Solution>(),
+        Day::new::<day_04::Solution>(),
+        Day::new::<day_05::Solution>(),
+        Day::new::<day_06::Solution>(),
+        Day::new::<day_07::Solution>(),
+        Day::new::<day_08::Solution>(),
+        Day::new::<day_09::Solution>(),
+        Day::new::<day_10::Solution>(),
+        Day::new::<day_11::Solution>(),
+        Day::new::<day_12::Solution>(),
+        Day::new::<day_13::Solution>(),
+        Day::new::<day_14::Solution>(),
+        Day::new::<day_15::Solution>(),
+        Day::new::<day_16::Solution>(),
+        Day::new::<day_17::Solution>(),
+        Day::new::<day_18::Solution>(),
+        Day::new::<day_19::Solution>(),
+        Day::new::<day_20::Solution>(),
+        Day::new::<day_21::Solution>(),
+        Day::new::<day_22::Solution>(),
+        Day::new::<day_23::Solution>(),
+        Day::new::<day_24::Solution>(),
+        Day::new::<day_25::Solution>(),
+    ]
+});
+
+/// All implemented days, ordered by day number
+pub fn days() -> &'static [Day] {
+    &DAYS
+}
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    days().iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_ordered() {
+        assert!(days()
+            .windows(2)
+            .all(|pair| pair[0].number < pair[1].number));
+    }
+
+    #[test]
+    fn test_all_workspace_days_registered() {
+        let manifest: toml::Table = include_str!("../../Cargo.toml").parse().unwrap();
+
+        let members = manifest["workspace"]["members"]
+            .as_array()
+            .expect("workspace members should be an array");
+
+        let workspace_days = members
+            .iter()
+            .filter_map(|member| member.as_str()?.strip_prefix("day-")?.parse().ok())
+            .collect::<Vec<u32>>();
+
+        assert!(!workspace_days.is_empty());
+        assert_eq!(
+            days().iter().map(Day::number).collect::<Vec<_>>(),
+            workspace_days,
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::{
+    error::Error,
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use advent_utils::Part;
+
+use registry::Day;
+
+#[derive(Debug)]
+pub(crate) struct Stats {
+    mean: Duration,
+    min: Duration,
+    max: Duration,
+    stddev: Duration,
+}
+
+impl Stats {
+    fn from_samples(samples: &[Duration]) -> Self {
+        debug_assert!(!samples.is_empty());
+
+        let secs = samples.iter().map(Duration::as_secs_f64);
+        let count = samples.len() as f64;
+
+        let mean = secs.clone().sum::<f64>() / count;
+        let variance = if samples.len() > 1 {
+            secs.map(|s| (s - mean).powi(2)).sum::<f64>() / (count - 1.0)
+        } else {
+            0.0
+        };
+
+        Self {
+            mean: Duration::from_secs_f64(mean),
+            min: samples.iter().min().copied().unwrap_or_default(),
+            max: samples.iter().max().copied().unwrap_or_default(),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "mean {:>10.2?} ± {:>10.2?}    min {:>10.2?}    max {:>10.2?}",
+            self.mean, self.stddev, self.min, self.max,
+        )
+    }
+}
+
+/// Runs `f` `warmup` times, then measures `iterations` more runs
+fn measure<T>(warmup: u32, iterations: u32, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+
+    let samples = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+
+    Stats::from_samples(&samples)
+}
+
+pub(crate) fn run(
+    day: &Day,
+    parts: &[Part],
+    input_data: &str,
+    warmup: u32,
+    iterations: u32,
+) -> Result<(), Box<dyn Error>> {
+    // fail early on invalid input, so that only successful runs are measured
+    let solver = day.parse(input_data)?;
+
+    let stats = measure(warmup, iterations, || {
+        day.parse(black_box(input_data)).is_ok()
+    });
+    println!("day {:02} parse:    {}", day.number(), stats);
+
+    for &part in parts {
+        let stats = measure(warmup, iterations, || solver.solve(black_box(part)));
+        println!("day {:02} part {:?}: {}", day.number(), part, stats);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_millis);
+
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.max, Duration::from_millis(9));
+        assert_eq!(stats.stddev.as_micros(), 2138);
+
+        let stats = Stats::from_samples(&[Duration::from_millis(3)]);
+
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,69 @@
+use advent_utils::Part;
+use clap::{Parser, ValueEnum};
+
+/// Run Advent of Code 2020 solutions
+#[derive(Debug, Parser)]
+#[command(name = "run-all")]
+pub(crate) struct Args {
+    /// Days to run (repeat the flag or separate with commas), all days if omitted
+    #[arg(short, long = "day", value_name = "DAY", value_delimiter = ',')]
+    pub days: Vec<u32>,
+
+    /// Run only the given part
+    #[arg(short, long, value_enum)]
+    pub part: Option<PartArg>,
+
+    /// Read input from this file (or stdin for `-`) instead of `$BASE_PATH/day-NN/input.txt`,
+    /// requires exactly one `--day`
+    #[arg(short, long, value_name = "PATH")]
+    pub input: Option<String>,
+
+    /// Output format for answers: `json` and `csv` emit one record per day and part
+    #[arg(short, long, value_enum, default_value_t = Format::Text, conflicts_with = "bench")]
+    pub format: Format,
+
+    /// Print only raw answers (one per line) instead of descriptive messages
+    #[arg(long, conflicts_with_all = ["format", "bench"])]
+    pub raw: bool,
+
+    /// Parse and solve all selected days and parts concurrently on a thread pool
+    /// (its size can be set via `RAYON_NUM_THREADS`)
+    #[arg(long, conflicts_with = "bench")]
+    pub parallel: bool,
+
+    /// Measure parsing and solving time instead of printing answers
+    #[arg(long)]
+    pub bench: bool,
+
+    /// Number of measured runs per day and part in `--bench` mode
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: u32,
+
+    /// Number of unmeasured runs preceding measurement in `--bench` mode
+    #[arg(long, default_value_t = 3)]
+    pub warmup: u32,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub(crate) enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub(crate) enum PartArg {
+    #[value(alias = "1")]
+    One,
+    #[value(alias = "2")]
+    Two,
+}
+
+impl From<PartArg> for Part {
+    fn from(part: PartArg) -> Self {
+        match part {
+            PartArg::One => Part::One,
+            PartArg::Two => Part::Two,
+        }
+    }
+}
@@ -1,37 +1,224 @@
-use std::{env::var, error::Error, str::FromStr};
+use std::{
+    env::var,
+    error::Error,
+    io::{self, Read},
+    path::Path,
+    process::ExitCode,
+    sync::LazyLock,
+    time::Instant,
+};
 
-use advent_utils::{read_file, Solver};
+use advent_utils::{read_file, Part};
+use clap::Parser;
+use displaydoc::Display;
+use inputs::Fetcher;
+use rayon::prelude::*;
+use thiserror::Error;
 
-fn run<S: Solver + FromStr<Err = Box<dyn Error>>>() -> Result<(), Box<dyn Error>> {
-    let input_file = format!(
+mod bench;
+mod cli;
+mod output;
+
+use cli::Args;
+use output::{Output, Record};
+use registry::Day;
+
+const STDIN_INPUT: &str = "-";
+
+/// Downloads inputs missing from `$BASE_PATH`, available if `AOC_SESSION` is set
+static FETCHER: LazyLock<Option<Fetcher>> = LazyLock::new(Fetcher::from_env);
+
+#[derive(Debug, Display, Error)]
+enum RunError {
+    /// day {0} is not implemented
+    UnknownDay(u32),
+    /// day {day:02} part {part:?} is not implemented
+    UnknownPart { day: u32, part: Part },
+    /// --input requires exactly one --day to be selected
+    AmbiguousInput,
+    /// some of the solutions failed
+    SolutionsFailed,
+}
+
+/// Resolves requested days and parts, failing early if any of them is not implemented
+fn select(args: &Args) -> Result<Vec<(&'static Day, Vec<Part>)>, RunError> {
+    let selected = if args.days.is_empty() {
+        registry::days().iter().collect()
+    } else {
+        args.days
+            .iter()
+            .map(|&number| registry::find(number).ok_or(RunError::UnknownDay(number)))
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    if args.input.is_some() && selected.len() != 1 {
+        return Err(RunError::AmbiguousInput);
+    }
+
+    let mut plan = Vec::with_capacity(selected.len());
+
+    for day in selected {
+        // when running every day, skip the ones nobody has an input for yet, but say so
+        let input_path = default_input_path(day);
+        if args.days.is_empty() && FETCHER.is_none() && !Path::new(&input_path).exists() {
+            eprintln!(
+                "skipping day {:02}: {} not found (set AOC_SESSION to download it)",
+                day.number(),
+                input_path
+            );
+            continue;
+        }
+
+        let implemented = day.implemented_parts();
+
+        let parts = match args.part.map(Part::from) {
+            Some(part) if implemented.contains(&part) => vec![part],
+            // when running every day, just skip the ones missing requested part
+            Some(_) if args.days.is_empty() => continue,
+            Some(part) => {
+                return Err(RunError::UnknownPart {
+                    day: day.number(),
+                    part,
+                })
+            }
+            None => implemented,
+        };
+
+        plan.push((day, parts));
+    }
+
+    Ok(plan)
+}
+
+fn read_input(day: &Day, input: Option<&str>) -> Result<String, Box<dyn Error>> {
+    match input {
+        Some(STDIN_INPUT) => {
+            let mut input_data = String::new();
+            io::stdin().read_to_string(&mut input_data)?;
+
+            Ok(input_data)
+        }
+        Some(path) => read_input_file(path.to_owned()),
+        None => {
+            let path = default_input_path(day);
+
+            match &*FETCHER {
+                Some(fetcher) if !Path::new(&path).exists() => Ok(fetcher.input(day.number())?),
+                _ => read_input_file(path),
+            }
+        }
+    }
+}
+
+fn default_input_path(day: &Day) -> String {
+    format!(
         "{}/day-{:02}/input.txt",
         var("BASE_PATH").unwrap_or_else(|_| ".".to_owned()),
-        S::day_number()
-    );
-    let input_data = read_file(input_file)?;
-    let solver: S = input_data.parse()?;
+        day.number()
+    )
+}
+
+fn read_input_file(path: String) -> Result<String, Box<dyn Error>> {
+    read_file(path.clone()).map_err(|e| format!("failed to read {}: {}", path, e).into())
+}
 
-    for part in S::implemented_parts() {
-        println!("day {:02}: {}", S::day_number(), solver.solve(part));
+/// Parses input and solves requested parts, recording failures instead of bailing out
+fn solve(day: &Day, parts: &[Part], input_data: &str, parallel: bool) -> Vec<Record> {
+    let start = Instant::now();
+    let solver = day.parse(input_data);
+    let parse_time = start.elapsed();
+
+    let solve_part = |&part: &Part| match &solver {
+        Ok(solver) => {
+            let start = Instant::now();
+            let solved = solver.solve(part);
+            let solve_time = start.elapsed();
+
+            match solved {
+                Ok(solved) => Record::answer(day.number(), part, solved, parse_time, solve_time),
+                Err(e) => Record::solve_error(day.number(), part, &e, parse_time, Some(solve_time)),
+            }
+        }
+        Err(e) => Record::solve_error(day.number(), part, e, parse_time, None),
+    };
+
+    if parallel {
+        parts.par_iter().map(solve_part).collect()
+    } else {
+        parts.iter().map(solve_part).collect()
     }
+}
 
-    Ok(())
+fn run_day(day: &Day, parts: &[Part], args: &Args) -> Vec<Record> {
+    match read_input(day, args.input.as_deref()) {
+        Ok(input_data) => solve(day, parts, &input_data, args.parallel),
+        Err(e) => parts
+            .iter()
+            .map(|&part| Record::input_error(day.number(), part, e.to_string()))
+            .collect(),
+    }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    run::<day_01::Solution>()?;
-    run::<day_02::Solution>()?;
-    run::<day_03::Solution>()?;
-    run::<day_04::Solution>()?;
-    run::<day_05::Solution>()?;
-    run::<day_06::Solution>()?;
-    run::<day_07::Solution>()?;
-    run::<day_08::Solution>()?;
-    run::<day_09::Solution>()?;
-    run::<day_10::Solution>()?;
-    run::<day_11::Solution>()?;
-    run::<day_12::Solution>()?;
-    run::<day_13::Solution>()?;
+fn run_selected(args: &Args) -> Result<(), Box<dyn Error>> {
+    let plan = select(args)?;
+
+    if args.bench {
+        for (day, parts) in plan {
+            let input_data = read_input(day, args.input.as_deref())?;
+
+            bench::run(day, &parts, &input_data, args.warmup, args.iterations)?;
+        }
+
+        return Ok(());
+    }
+
+    let mut output = Output::new(args.format, args.raw);
+    let mut failed = false;
+
+    let mut emit = |records: Vec<Record>| -> Result<(), Box<dyn Error>> {
+        for record in records {
+            failed |= record.is_error();
+
+            output.write(&record)?;
+        }
+
+        Ok(())
+    };
+
+    if args.parallel {
+        // results are collected in plan order, so output stays deterministic
+        let records = plan
+            .par_iter()
+            .map(|(day, parts)| run_day(day, parts, args))
+            .collect::<Vec<_>>();
+
+        for day_records in records {
+            emit(day_records)?;
+        }
+    } else {
+        for (day, parts) in &plan {
+            emit(run_day(day, parts, args))?;
+        }
+    }
+
+    output.finish()?;
+
+    if failed {
+        return Err(RunError::SolutionsFailed.into());
+    }
 
     Ok(())
 }
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    match run_selected(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::{error::Error, io, time::Duration};
+
+use advent_utils::Part;
+use common::{SolveError, Solved};
+use serde::Serialize;
+
+use crate::cli::Format;
+
+/// Outcome of solving one part of a day
+#[derive(Debug, Serialize)]
+pub(crate) struct Record {
+    day: u32,
+    part: &'static str,
+    answer: Option<String>,
+    raw_answer: Option<String>,
+    parse_time_ns: Option<u64>,
+    solve_time_ns: Option<u64>,
+    error_kind: Option<&'static str>,
+    error: Option<String>,
+}
+
+fn part_name(part: Part) -> &'static str {
+    match part {
+        Part::One => "one",
+        Part::Two => "two",
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
+
+impl Record {
+    pub(crate) fn answer(
+        day: u32,
+        part: Part,
+        solved: Solved,
+        parse_time: Duration,
+        solve_time: Duration,
+    ) -> Self {
+        Self {
+            day,
+            part: part_name(part),
+            answer: Some(solved.message),
+            raw_answer: Some(solved.answer.to_string()),
+            parse_time_ns: Some(nanos(parse_time)),
+            solve_time_ns: Some(nanos(solve_time)),
+            error_kind: None,
+            error: None,
+        }
+    }
+
+    fn error(day: u32, part: Part, kind: &'static str, error: String) -> Self {
+        Self {
+            day,
+            part: part_name(part),
+            answer: None,
+            raw_answer: None,
+            parse_time_ns: None,
+            solve_time_ns: None,
+            error_kind: Some(kind),
+            error: Some(error),
+        }
+    }
+
+    pub(crate) fn input_error(day: u32, part: Part, error: String) -> Self {
+        Self::error(day, part, "input", error)
+    }
+
+    pub(crate) fn solve_error(
+        day: u32,
+        part: Part,
+        error: &SolveError,
+        parse_time: Duration,
+        solve_time: Option<Duration>,
+    ) -> Self {
+        let kind = match error {
+            SolveError::Parse(_) => "parse",
+            SolveError::NoSolution(_) => "no_solution",
+            SolveError::NotImplemented(_) => "not_implemented",
+        };
+
+        Self {
+            parse_time_ns: Some(nanos(parse_time)),
+            solve_time_ns: solve_time.map(nanos),
+            ..Self::error(day, part, kind, error.to_string())
+        }
+    }
+
+    pub(crate) fn is_error(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+/// Writes records to stdout in the requested format
+pub(crate) enum Output {
+    Text,
+    Raw,
+    Json,
+    Csv(Box<csv::Writer<io::Stdout>>),
+}
+
+impl Output {
+    pub(crate) fn new(format: Format, raw: bool) -> Self {
+        match format {
+            Format::Text if raw => Self::Raw,
+            Format::Text => Self::Text,
+            Format::Json => Self::Json,
+            Format::Csv => Self::Csv(Box::new(csv::Writer::from_writer(io::stdout()))),
+        }
+    }
+
+    pub(crate) fn write(&mut self, record: &Record) -> Result<(), Box<dyn Error>> {
+        match self {
+            Self::Text => match (&record.answer, &record.error) {
+                (Some(answer), _) => println!("day {:02}: {}", record.day, answer),
+                (None, Some(error)) => {
+                    eprintln!(
+                        "error: day {:02} part {}: {}",
+                        record.day, record.part, error
+                    )
+                }
+                (None, None) => {}
+            },
+            Self::Raw => match (&record.raw_answer, &record.error) {
+                (Some(answer), _) => println!("{}", answer),
+                (None, Some(error)) => {
+                    eprintln!(
+                        "error: day {:02} part {}: {}",
+                        record.day, record.part, error
+                    )
+                }
+                (None, None) => {}
+            },
+            Self::Json => println!("{}", serde_json::to_string(record)?),
+            Self::Csv(writer) => writer.serialize(record)?,
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn finish(self) -> Result<(), Box<dyn Error>> {
+        if let Self::Csv(mut writer) = self {
+            writer.flush()?;
+        }
+
+        Ok(())
+    }
+}